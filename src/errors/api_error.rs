@@ -19,14 +19,17 @@
 
 use axum::response::{IntoResponse, Response};
 use http::StatusCode;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
+use super::redaction::redact_details;
+
 /// Standard JSON error response body.
 ///
 /// This structure is returned for all API errors, providing
 /// a consistent format for clients.
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct ErrorResponse {
     /// Short error description (e.g., "Not Found", "Bad Request").
     pub error: String,
@@ -139,6 +142,22 @@ impl ApiError {
         }
     }
 
+    /// Get a stable, language-neutral key for this error kind.
+    ///
+    /// Used to look up translations in an [`ErrorCatalog`](crate::ErrorCatalog).
+    pub fn error_key(&self) -> &'static str {
+        match self {
+            ApiError::BadRequest(_) => "bad-request",
+            ApiError::Unauthorized(_) => "unauthorized",
+            ApiError::Forbidden(_) => "forbidden",
+            ApiError::NotFound(_) => "not-found",
+            ApiError::Conflict(_) => "conflict",
+            ApiError::ValidationError(_) => "validation-error",
+            ApiError::Internal(_) => "internal",
+            ApiError::ServiceUnavailable(_) => "service-unavailable",
+        }
+    }
+
     /// Check if this is a client error (4xx).
     pub fn is_client_error(&self) -> bool {
         self.status_code().is_client_error()
@@ -151,8 +170,8 @@ impl ApiError {
 }
 
 impl ApiError {
-    /// Extracts the inner message from this error.
-    fn into_message(self) -> String {
+    /// Returns the inner message of this error.
+    fn message(&self) -> &str {
         match self {
             ApiError::BadRequest(msg)
             | ApiError::Unauthorized(msg)
@@ -164,26 +183,38 @@ impl ApiError {
             | ApiError::ServiceUnavailable(msg) => msg,
         }
     }
-}
-
-impl IntoResponse for ApiError {
-    fn into_response(self) -> Response {
-        let status = self.status_code();
-        let label = self.error_label();
 
+    /// Builds the response body for this error.
+    ///
+    /// Details of internal errors are not exposed, all other details
+    /// are passed through the installed [`Redactor`](crate::Redactor), if any.
+    pub fn to_error_response(&self) -> ErrorResponse {
         // For internal errors, don't expose details to clients
         let details = if matches!(self, ApiError::Internal(_)) {
             None
         } else {
-            Some(self.into_message())
+            Some(redact_details(self.message().to_string()))
         };
 
-        let body = ErrorResponse {
-            error: label.to_string(),
+        ErrorResponse {
+            error: self.error_label().to_string(),
             details,
-        };
+        }
+    }
+}
+
+impl IntoResponse for ApiError {
+    fn into_response(self) -> Response {
+        let status = self.status_code();
+
+        #[cfg(feature = "metrics")]
+        super::metrics::record_error(status, self.error_label(), None);
 
-        (status, axum::Json(body)).into_response()
+        let body = self.to_error_response();
+        let mut response = (status, axum::Json(body)).into_response();
+        // Lets middleware such as `localize_errors` inspect the original error
+        response.extensions_mut().insert(self);
+        response
     }
 }
 
@@ -238,10 +269,11 @@ impl<E: IntoApiError> From<E> for ApiError {
 /// Helper to create a JSON error response tuple.
 ///
 /// Useful when you need more control over the response.
+/// Details are passed through the installed [`Redactor`](crate::Redactor), if any.
 pub fn json_error(status: StatusCode, error: &str, details: Option<String>) -> Response {
     let body = ErrorResponse {
         error: error.to_string(),
-        details,
+        details: details.map(redact_details),
     };
     (status, axum::Json(body)).into_response()
 }
@@ -0,0 +1,252 @@
+// Copyright (C) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Localization of error labels and messages.
+//!
+//! [`ApiError`] responses carry the original error in their extensions.
+//! The [`localize_errors`] middleware picks it up, resolves the label and
+//! message for the request's `Accept-Language` from an [`ErrorCatalog`]
+//! and rewrites the response body. English is used when the catalog
+//! has no translation for any of the accepted languages.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use axum::extract::{Request, State};
+use axum::middleware::Next;
+use axum::response::{IntoResponse, Response};
+use http::{HeaderValue, header};
+
+use super::api_error::{ApiError, ErrorResponse};
+use super::redaction::redact_details;
+
+/// Placeholder for the original error details in message templates.
+pub const DETAILS_PLACEHOLDER: &str = "{details}";
+
+/// A source of localized error labels and messages.
+///
+/// Implement this trait to plug in a translation framework such as Fluent.
+/// Keys are the values returned by [`ApiError::error_key`].
+pub trait ErrorCatalog: Send + Sync + 'static {
+    /// Returns the label for the given language and error key, if translated.
+    fn label(&self, lang: &str, key: &str) -> Option<String>;
+
+    /// Returns the message for the given language and error key, if translated.
+    ///
+    /// `details` is the original (English) error message.
+    fn message(&self, _lang: &str, _key: &str, _details: &str) -> Option<String> {
+        None
+    }
+}
+
+/// A shared, type-erased error catalog, used as the [`localize_errors`] middleware state.
+pub type SharedErrorCatalog = Arc<dyn ErrorCatalog>;
+
+/// A simple in-memory [`ErrorCatalog`].
+///
+/// Message templates can reference the original error details
+/// with the `{details}` placeholder.
+///
+/// # Example
+///
+/// ```
+/// use bel7_axum::{ErrorCatalog, StaticCatalog};
+///
+/// let catalog = StaticCatalog::new()
+///     .with_label("de", "not-found", "Nicht gefunden")
+///     .with_message("de", "not-found", "Ressource nicht gefunden: {details}");
+///
+/// assert_eq!(catalog.label("de", "not-found").as_deref(), Some("Nicht gefunden"));
+/// assert_eq!(
+///     catalog.message("de", "not-found", "user 7").as_deref(),
+///     Some("Ressource nicht gefunden: user 7")
+/// );
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct StaticCatalog {
+    labels: HashMap<(String, String), String>,
+    messages: HashMap<(String, String), String>,
+}
+
+impl StaticCatalog {
+    /// Creates an empty catalog.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a translated label.
+    pub fn with_label(
+        mut self,
+        lang: impl Into<String>,
+        key: impl Into<String>,
+        label: impl Into<String>,
+    ) -> Self {
+        self.labels
+            .insert((lang.into().to_ascii_lowercase(), key.into()), label.into());
+        self
+    }
+
+    /// Adds a translated message template.
+    pub fn with_message(
+        mut self,
+        lang: impl Into<String>,
+        key: impl Into<String>,
+        template: impl Into<String>,
+    ) -> Self {
+        self.messages.insert(
+            (lang.into().to_ascii_lowercase(), key.into()),
+            template.into(),
+        );
+        self
+    }
+}
+
+impl ErrorCatalog for StaticCatalog {
+    fn label(&self, lang: &str, key: &str) -> Option<String> {
+        self.labels
+            .get(&(lang.to_ascii_lowercase(), key.to_string()))
+            .cloned()
+    }
+
+    fn message(&self, lang: &str, key: &str, details: &str) -> Option<String> {
+        self.messages
+            .get(&(lang.to_ascii_lowercase(), key.to_string()))
+            .map(|template| template.replace(DETAILS_PLACEHOLDER, details))
+    }
+}
+
+/// Parses an `Accept-Language` header value into language tags ordered by preference.
+///
+/// Wildcards and languages with `q=0` are skipped.
+///
+/// # Example
+///
+/// ```
+/// use bel7_axum::accepted_languages;
+///
+/// assert_eq!(
+///     accepted_languages("fr-CH, fr;q=0.9, en;q=0.8, *;q=0.5"),
+///     vec!["fr-CH", "fr", "en"]
+/// );
+/// ```
+pub fn accepted_languages(header_value: &str) -> Vec<String> {
+    let mut langs: Vec<(String, f32)> = header_value
+        .split(',')
+        .filter_map(|entry| {
+            let mut parts = entry.split(';');
+            let tag = parts.next()?.trim();
+            if tag.is_empty() || tag == "*" {
+                return None;
+            }
+            let q = parts
+                .filter_map(|p| p.trim().strip_prefix("q="))
+                .find_map(|q| q.trim().parse::<f32>().ok())
+                .unwrap_or(1.0);
+            (q > 0.0).then(|| (tag.to_string(), q))
+        })
+        .collect();
+    // stable sort keeps the header order for equal weights
+    langs.sort_by(|a, b| b.1.total_cmp(&a.1));
+    langs.into_iter().map(|(tag, _)| tag).collect()
+}
+
+/// Middleware that localizes [`ApiError`] responses using an [`ErrorCatalog`].
+///
+/// For every accepted language, the full tag (e.g. `de-AT`) is tried first,
+/// then its primary subtag (`de`). Responses are left untouched when no
+/// translation is found. Localized responses get a `Content-Language` header.
+/// Every response gets `Vary: Accept-Language`, including the English
+/// fallback served to requests without the header, so that shared caches
+/// keep languages apart.
+///
+/// # Example
+///
+/// ```ignore
+/// use std::sync::Arc;
+/// use axum::{Router, middleware};
+/// use bel7_axum::{SharedErrorCatalog, StaticCatalog, localize_errors};
+///
+/// let catalog: SharedErrorCatalog = Arc::new(
+///     StaticCatalog::new().with_label("de", "not-found", "Nicht gefunden"),
+/// );
+///
+/// let app = Router::new()
+///     .route("/users/{id}", get(get_user))
+///     .layer(middleware::from_fn_with_state(catalog, localize_errors));
+/// ```
+pub async fn localize_errors(
+    State(catalog): State<SharedErrorCatalog>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let langs = request
+        .headers()
+        .get(header::ACCEPT_LANGUAGE)
+        .and_then(|v| v.to_str().ok())
+        .map(accepted_languages)
+        .unwrap_or_default();
+
+    let mut response = next.run(request).await;
+    // the body depends on Accept-Language, shared caches must not mix languages
+    response
+        .headers_mut()
+        .append(header::VARY, HeaderValue::from_static("accept-language"));
+    if langs.is_empty() {
+        return response;
+    }
+    let Some(err) = response.extensions().get::<ApiError>().cloned() else {
+        return response;
+    };
+
+    match localize(catalog.as_ref(), &langs, &err) {
+        Some((lang, body)) => {
+            let (mut parts, _) = response.into_parts();
+            parts.headers.remove(header::CONTENT_LENGTH);
+            if let Ok(value) = HeaderValue::from_str(&lang) {
+                parts.headers.insert(header::CONTENT_LANGUAGE, value);
+            }
+            let body = axum::Json(body).into_response().into_body();
+            Response::from_parts(parts, body)
+        }
+        None => response,
+    }
+}
+
+fn localize(
+    catalog: &dyn ErrorCatalog,
+    langs: &[String],
+    err: &ApiError,
+) -> Option<(String, ErrorResponse)> {
+    let key = err.error_key();
+    let original = err.to_error_response();
+
+    for tag in langs {
+        let primary = tag.split('-').next().unwrap_or(tag);
+        for lang in [tag.as_str(), primary] {
+            let label = catalog.label(lang, key);
+            let message = original
+                .details
+                .as_deref()
+                .and_then(|details| catalog.message(lang, key, details));
+            if label.is_some() || message.is_some() {
+                let body = ErrorResponse {
+                    error: label.unwrap_or(original.error),
+                    details: message.map(redact_details).or(original.details),
+                };
+                return Some((lang.to_string(), body));
+            }
+        }
+    }
+    None
+}
@@ -0,0 +1,119 @@
+// Copyright (C) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! API error counters exposed in the Prometheus text format.
+
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::sync::Mutex;
+
+use axum::response::{IntoResponse, Response};
+use http::{StatusCode, header};
+
+/// Name of the counter metric that tracks API error responses.
+pub const API_ERRORS_METRIC: &str = "api_errors_total";
+
+/// Content type of the Prometheus text exposition format.
+pub const PROMETHEUS_CONTENT_TYPE: &str = "text/plain; version=0.0.4; charset=utf-8";
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+struct ErrorKey {
+    status: u16,
+    error: String,
+    code: Option<String>,
+}
+
+static ERROR_COUNTERS: Mutex<BTreeMap<ErrorKey, u64>> = Mutex::new(BTreeMap::new());
+
+/// Increments the error counter for the given status, error label and optional code.
+///
+/// [`ApiError`](crate::ApiError) responses are recorded automatically. Use this
+/// function for error responses produced by other means, e.g. [`json_error`](crate::json_error).
+pub fn record_error(status: StatusCode, error: &str, code: Option<&str>) {
+    let key = ErrorKey {
+        status: status.as_u16(),
+        error: error.to_string(),
+        code: code.map(str::to_string),
+    };
+    let mut counters = ERROR_COUNTERS.lock().unwrap_or_else(|e| e.into_inner());
+    *counters.entry(key).or_insert(0) += 1;
+}
+
+/// Returns the current counter value for the given status, error label and optional code.
+pub fn error_count(status: StatusCode, error: &str, code: Option<&str>) -> u64 {
+    let key = ErrorKey {
+        status: status.as_u16(),
+        error: error.to_string(),
+        code: code.map(str::to_string),
+    };
+    let counters = ERROR_COUNTERS.lock().unwrap_or_else(|e| e.into_inner());
+    counters.get(&key).copied().unwrap_or(0)
+}
+
+/// Renders all error counters in the Prometheus text exposition format.
+pub fn render_metrics() -> String {
+    let counters = ERROR_COUNTERS.lock().unwrap_or_else(|e| e.into_inner());
+
+    let mut out = String::new();
+    let _ = writeln!(
+        out,
+        "# HELP {API_ERRORS_METRIC} Number of API error responses produced."
+    );
+    let _ = writeln!(out, "# TYPE {API_ERRORS_METRIC} counter");
+    for (key, value) in counters.iter() {
+        let _ = write!(
+            out,
+            "{API_ERRORS_METRIC}{{status=\"{}\",error=\"{}\"",
+            key.status,
+            escape_label_value(&key.error)
+        );
+        if let Some(code) = &key.code {
+            let _ = write!(out, ",code=\"{}\"", escape_label_value(code));
+        }
+        let _ = writeln!(out, "}} {value}");
+    }
+    out
+}
+
+/// Handler that exposes the error counters in the Prometheus text format.
+///
+/// # Example
+///
+/// ```ignore
+/// use axum::{Router, routing::get};
+/// use bel7_axum::metrics_handler;
+///
+/// let app = Router::new().route("/metrics", get(metrics_handler));
+/// ```
+pub async fn metrics_handler() -> Response {
+    (
+        StatusCode::OK,
+        [(header::CONTENT_TYPE, PROMETHEUS_CONTENT_TYPE)],
+        render_metrics(),
+    )
+        .into_response()
+}
+
+fn escape_label_value(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '\\' => escaped.push_str("\\\\"),
+            '"' => escaped.push_str("\\\""),
+            '\n' => escaped.push_str("\\n"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
@@ -19,9 +19,20 @@
 //! - [`IntoApiError`] - Trait for converting domain errors to API errors
 //! - [`ErrorResponse`] - Standard JSON error response body
 //! - Error classification traits ([`RecoverableError`], [`ConnectionError`], etc.)
+//! - [`Redactor`] - Removes secrets from error details before serialization
+//! - [`ErrorCatalog`] and [`localize_errors`] - Localized error labels and messages
+//! - Error counters in the Prometheus text format (requires `metrics` feature)
 
 mod api_error;
+mod i18n;
+#[cfg(feature = "metrics")]
+mod metrics;
+mod redaction;
 mod traits;
 
 pub use api_error::*;
+pub use i18n::*;
+#[cfg(feature = "metrics")]
+pub use metrics::*;
+pub use redaction::*;
 pub use traits::*;
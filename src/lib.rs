@@ -17,30 +17,55 @@
 //! This crate provides reusable components for Axum-based web servers:
 //!
 //! - Error handling with [`ApiError`] and [`IntoApiError`]
+//! - Redaction of secrets in error details with [`Redactor`]
+//! - Localized error labels and messages via [`ErrorCatalog`]
 //! - Static file serving with SPA routing support (requires `embed` feature)
-//! - Pagination response wrappers
+//! - Offset-, page number- and cursor-based pagination response wrappers
 //! - WebSocket connection helpers (requires `websocket` feature)
+//! - Prometheus-style API error counters (requires `metrics` feature)
+//! - OpenAPI schemas and response definitions (requires `openapi` feature)
+//! - Test assertion helpers and an in-process test client (requires `testing` feature)
 //!
 //! # Features
 //!
 //! - `embed` - Enables `rust-embed` based static file serving
 //! - `websocket` - Enables WebSocket utilities with timeout handling
+//! - `metrics` - Enables API error counters and a Prometheus metrics handler
+//! - `regex` - Enables regular expression rules for [`Redactor`]
+//! - `openapi` - Enables `utoipa` schemas for the response and query types
+//! - `cursor-codec` - Enables opaque, HMAC-signed and expiring pagination cursors
+//! - `stream` - Enables `Stream`-based pagination utilities and streaming responses
+//! - `sqlx` - Enables `LIMIT`/`OFFSET` generation for `sqlx::QueryBuilder`
+//! - `csv` - Enables CSV rendering of paginated responses
+//! - `testing` - Enables assertion helpers and a test client for integration tests
 //! - `full` - Enables all features
 
 mod errors;
 mod pagination;
 
+#[cfg(feature = "openapi")]
+mod openapi;
+
 #[cfg(feature = "embed")]
 mod static_files;
 
+#[cfg(feature = "testing")]
+mod testing;
+
 #[cfg(feature = "websocket")]
 mod websocket;
 
 pub use errors::*;
 pub use pagination::*;
 
+#[cfg(feature = "openapi")]
+pub use openapi::*;
+
 #[cfg(feature = "embed")]
 pub use static_files::*;
 
+#[cfg(feature = "testing")]
+pub use testing::*;
+
 #[cfg(feature = "websocket")]
 pub use websocket::*;
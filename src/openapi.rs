@@ -0,0 +1,82 @@
+// Copyright (C) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! OpenAPI response definitions for [`ApiError`] variants.
+//!
+//! Schemas for [`ErrorResponse`](crate::ErrorResponse), [`PaginatedResponse`](crate::PaginatedResponse)
+//! and [`PaginationQuery`](crate::PaginationQuery) are derived on the types themselves.
+
+use std::collections::BTreeMap;
+
+use utoipa::openapi::{ContentBuilder, Ref, RefOr, Response, ResponseBuilder};
+use utoipa::{IntoResponses, ToResponse};
+
+use crate::ApiError;
+
+fn error_response(description: &str) -> Response {
+    ResponseBuilder::new()
+        .description(description)
+        .content(
+            "application/json",
+            ContentBuilder::new()
+                .schema(Some(Ref::from_schema_name("ErrorResponse")))
+                .build(),
+        )
+        .build()
+}
+
+macro_rules! error_responses {
+    ($($(#[$meta:meta])* $name:ident => $status:literal, $description:literal;)*) => {
+        $(
+            $(#[$meta])*
+            pub struct $name;
+
+            impl<'r> ToResponse<'r> for $name {
+                fn response() -> (&'r str, RefOr<Response>) {
+                    (stringify!($name), error_response($description).into())
+                }
+            }
+        )*
+
+        /// Lists every status code [`ApiError`] can produce.
+        ///
+        /// Use it as `responses(ApiError)` in `#[utoipa::path]`.
+        impl IntoResponses for ApiError {
+            fn responses() -> BTreeMap<String, RefOr<Response>> {
+                BTreeMap::from([
+                    $(($status.to_string(), error_response($description).into()),)*
+                ])
+            }
+        }
+    };
+}
+
+error_responses! {
+    /// Response definition for [`ApiError::BadRequest`].
+    BadRequestResponse => "400", "Bad Request";
+    /// Response definition for [`ApiError::Unauthorized`].
+    UnauthorizedResponse => "401", "Unauthorized";
+    /// Response definition for [`ApiError::Forbidden`].
+    ForbiddenResponse => "403", "Forbidden";
+    /// Response definition for [`ApiError::NotFound`].
+    NotFoundResponse => "404", "Not Found";
+    /// Response definition for [`ApiError::Conflict`].
+    ConflictResponse => "409", "Conflict";
+    /// Response definition for [`ApiError::ValidationError`].
+    ValidationErrorResponse => "422", "Validation Error";
+    /// Response definition for [`ApiError::Internal`].
+    InternalErrorResponse => "500", "Internal Server Error";
+    /// Response definition for [`ApiError::ServiceUnavailable`].
+    ServiceUnavailableResponse => "503", "Service Unavailable";
+}
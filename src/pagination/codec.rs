@@ -0,0 +1,193 @@
+// Copyright (C) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Opaque, optionally signed and expiring pagination cursors.
+
+use std::fmt;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use base64::Engine;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use hmac::{Hmac, Mac};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+
+use super::CursorQuery;
+use crate::ApiError;
+
+type HmacSha256 = Hmac<Sha256>;
+
+#[derive(Serialize)]
+struct EncodedPayload<'a, K> {
+    k: &'a K,
+    /// Expiry, in milliseconds since the Unix epoch.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    exp: Option<u64>,
+}
+
+#[derive(Deserialize)]
+struct DecodedPayload<K> {
+    k: K,
+    #[serde(default)]
+    exp: Option<u64>,
+}
+
+/// Encodes pagination keys into opaque cursors and decodes them back.
+///
+/// Keys can be of any `Serialize` type (e.g. a tuple of the sort columns).
+/// Cursors are base64url-encoded and, when a secret is configured,
+/// signed with HMAC-SHA256 so that clients cannot craft their own.
+/// A TTL makes cursors expire. Since the expiry is stored in the cursor,
+/// a TTL requires a secret: unsigned cursors could be edited to never expire.
+///
+/// Decoding failures are reported as [`ApiError::BadRequest`].
+///
+/// # Example
+///
+/// ```
+/// use std::time::Duration;
+/// use bel7_axum::CursorCodec;
+///
+/// let codec = CursorCodec::signed("server secret").with_ttl(Duration::from_secs(3600));
+///
+/// let cursor = codec.encode(&("2025-01-01", 42u64)).unwrap();
+/// let key: (String, u64) = codec.decode(&cursor).unwrap();
+/// assert_eq!(key, ("2025-01-01".to_string(), 42));
+///
+/// assert!(codec.decode::<(String, u64)>("forged").is_err());
+/// ```
+#[derive(Clone, Default)]
+pub struct CursorCodec {
+    secret: Option<Vec<u8>>,
+    ttl: Option<Duration>,
+}
+
+impl fmt::Debug for CursorCodec {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CursorCodec")
+            .field("signed", &self.secret.is_some())
+            .field("ttl", &self.ttl)
+            .finish()
+    }
+}
+
+impl CursorCodec {
+    /// Creates a codec that produces unsigned, non-expiring cursors.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a codec that signs cursors with the given secret.
+    pub fn signed(secret: impl AsRef<[u8]>) -> Self {
+        Self::new().with_secret(secret)
+    }
+
+    /// Sets the secret used to sign cursors.
+    pub fn with_secret(mut self, secret: impl AsRef<[u8]>) -> Self {
+        self.secret = Some(secret.as_ref().to_vec());
+        self
+    }
+
+    /// Sets how long cursors remain valid after they were issued.
+    ///
+    /// Requires a secret, see [`CursorCodec::with_secret`]: without one,
+    /// [`CursorCodec::encode`] fails.
+    pub fn with_ttl(mut self, ttl: Duration) -> Self {
+        self.ttl = Some(ttl);
+        self
+    }
+
+    /// Encodes a key into a cursor.
+    ///
+    /// Returns [`ApiError::Internal`] if the key cannot be serialized,
+    /// or if a TTL is set without a secret.
+    pub fn encode<K: Serialize>(&self, key: &K) -> Result<String, ApiError> {
+        if self.ttl.is_some() && self.secret.is_none() {
+            return Err(ApiError::Internal(
+                "expiring cursors require a signing secret".into(),
+            ));
+        }
+        let exp = self.ttl.map(|ttl| {
+            let ttl = u64::try_from(ttl.as_millis()).unwrap_or(u64::MAX);
+            unix_now_millis().saturating_add(ttl)
+        });
+        let payload = serde_json::to_vec(&EncodedPayload { k: key, exp })
+            .map_err(|e| ApiError::Internal(format!("failed to encode cursor: {e}")))?;
+
+        let mut cursor = URL_SAFE_NO_PAD.encode(payload);
+        if let Some(mac) = self.mac(cursor.as_bytes()) {
+            cursor.push('.');
+            cursor.push_str(&URL_SAFE_NO_PAD.encode(mac.finalize().into_bytes()));
+        }
+        Ok(cursor)
+    }
+
+    /// Decodes a cursor back into a key.
+    ///
+    /// Returns [`ApiError::BadRequest`] if the cursor is malformed,
+    /// its signature does not match or it has expired.
+    pub fn decode<K: DeserializeOwned>(&self, cursor: &str) -> Result<K, ApiError> {
+        let payload = match (self.secret.is_some(), cursor.split_once('.')) {
+            (true, Some((payload, signature))) => {
+                let signature = URL_SAFE_NO_PAD
+                    .decode(signature)
+                    .map_err(|_| invalid_cursor())?;
+                let mac = self.mac(payload.as_bytes()).ok_or_else(invalid_cursor)?;
+                mac.verify_slice(&signature).map_err(|_| invalid_cursor())?;
+                payload
+            }
+            (false, None) => cursor,
+            _ => return Err(invalid_cursor()),
+        };
+
+        let bytes = URL_SAFE_NO_PAD
+            .decode(payload)
+            .map_err(|_| invalid_cursor())?;
+        let decoded: DecodedPayload<K> =
+            serde_json::from_slice(&bytes).map_err(|_| invalid_cursor())?;
+
+        if decoded.exp.is_some_and(|exp| unix_now_millis() >= exp) {
+            return Err(ApiError::BadRequest("Cursor has expired".into()));
+        }
+        Ok(decoded.k)
+    }
+
+    /// Decodes the position of a [`CursorQuery`] ([`CursorQuery::position`]), if present.
+    pub fn decode_query<K: DeserializeOwned>(
+        &self,
+        query: &CursorQuery,
+    ) -> Result<Option<K>, ApiError> {
+        query.position().map(|c| self.decode(c)).transpose()
+    }
+
+    fn mac(&self, data: &[u8]) -> Option<HmacSha256> {
+        let secret = self.secret.as_ref()?;
+        // HMAC accepts keys of any length
+        let mut mac = HmacSha256::new_from_slice(secret).ok()?;
+        mac.update(data);
+        Some(mac)
+    }
+}
+
+fn invalid_cursor() -> ApiError {
+    ApiError::BadRequest("Invalid cursor".into())
+}
+
+fn unix_now_millis() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| u64::try_from(d.as_millis()).unwrap_or(u64::MAX))
+        .unwrap_or(0)
+}
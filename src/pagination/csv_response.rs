@@ -0,0 +1,370 @@
+// Copyright (C) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! CSV rendering of paginated responses.
+
+use axum::Json;
+use axum::extract::FromRequestParts;
+use axum::response::{IntoResponse, Response};
+use http::request::Parts;
+use http::{HeaderValue, header};
+use serde::Serialize;
+
+use super::PaginatedResponse;
+use crate::ApiError;
+
+/// `Content-Type` of CSV responses.
+pub const CSV_CONTENT_TYPE: &str = "text/csv; charset=utf-8";
+
+/// The representation of a list response requested by the client.
+///
+/// Extracted from the `format` query parameter (`json` or `csv`) or,
+/// if it is absent, from the `Accept` header. Defaults to JSON.
+/// Unknown `format` values are rejected with [`ApiError::BadRequest`].
+///
+/// # Example
+///
+/// ```ignore
+/// use bel7_axum::{PaginatedResponse, Pagination, ResponseFormat};
+///
+/// // GET /users?format=csv or Accept: text/csv
+/// async fn list_users(pagination: Pagination, format: ResponseFormat) -> Response {
+///     let page = pagination.response(repo.list(&pagination).await, repo.count().await);
+///     page.into_response_as(format, &["id", "name"])
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum ResponseFormat {
+    /// `application/json`.
+    #[default]
+    Json,
+
+    /// `text/csv`.
+    Csv,
+}
+
+impl ResponseFormat {
+    /// Parses a `format` query parameter value.
+    pub fn from_param(value: &str) -> Result<Self, ApiError> {
+        match value.to_ascii_lowercase().as_str() {
+            "json" => Ok(ResponseFormat::Json),
+            "csv" => Ok(ResponseFormat::Csv),
+            _ => Err(ApiError::BadRequest(format!(
+                "format must be 'json' or 'csv', got '{value}'"
+            ))),
+        }
+    }
+
+    /// Picks a format from an `Accept` header value.
+    ///
+    /// The media type with the highest quality wins, the first one on ties.
+    /// Returns JSON if neither `text/csv` nor `application/json` is acceptable.
+    pub fn from_accept(accept: &str) -> Self {
+        let mut best: Option<(ResponseFormat, f32)> = None;
+        for range in accept.split(',') {
+            let mut params = range.split(';');
+            let format = match params.next().unwrap_or("").trim() {
+                "text/csv" => ResponseFormat::Csv,
+                "application/json" => ResponseFormat::Json,
+                _ => continue,
+            };
+            let quality = params
+                .filter_map(|param| param.trim().strip_prefix("q="))
+                .find_map(|q| q.parse::<f32>().ok())
+                .unwrap_or(1.0);
+            if quality > 0.0 && best.is_none_or(|(_, q)| quality > q) {
+                best = Some((format, quality));
+            }
+        }
+        best.map(|(format, _)| format).unwrap_or_default()
+    }
+}
+
+impl<S> FromRequestParts<S> for ResponseFormat
+where
+    S: Send + Sync,
+{
+    type Rejection = ApiError;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        let query = parts.uri.query().unwrap_or("");
+        if let Some((_, value)) =
+            form_urlencoded::parse(query.as_bytes()).find(|(k, _)| k == "format")
+        {
+            return Self::from_param(&value);
+        }
+
+        Ok(parts
+            .headers
+            .get(header::ACCEPT)
+            .and_then(|accept| accept.to_str().ok())
+            .map(Self::from_accept)
+            .unwrap_or_default())
+    }
+}
+
+impl<T: Serialize> PaginatedResponse<T> {
+    /// Serializes this page's items as CSV, with a header row.
+    ///
+    /// For structs, the header row holds the field names written by `csv`.
+    /// `headers` must list the same names in the same order, or
+    /// [`ApiError::Internal`] is returned. It is used as the header row of
+    /// empty pages and of items without field names, such as tuples. Items
+    /// must serialize to flat records (structs or tuples without nested
+    /// containers) with one field per header.
+    ///
+    /// Cells that a spreadsheet could evaluate as a formula (starting with
+    /// `=`, `+`, `-`, `@`, a tab or a carriage return, unless they are
+    /// numbers) are prefixed with a single quote.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bel7_axum::PaginatedResponse;
+    /// use serde::Serialize;
+    ///
+    /// #[derive(Serialize)]
+    /// struct User {
+    ///     id: u32,
+    ///     name: &'static str,
+    /// }
+    ///
+    /// let page = PaginatedResponse::single_page(vec![User { id: 1, name: "alice" }]);
+    /// assert_eq!(page.to_csv(&["id", "name"]).unwrap(), b"id,name\n1,alice\n");
+    ///
+    /// let empty = PaginatedResponse::<User>::single_page(vec![]);
+    /// assert_eq!(empty.to_csv(&["id", "name"]).unwrap(), b"id,name\n");
+    /// ```
+    pub fn to_csv(&self, headers: &[&str]) -> Result<Vec<u8>, ApiError> {
+        let mut body = header_row(headers)?;
+        for item in &self.data {
+            body.extend(item_row(item, headers)?);
+        }
+        Ok(body)
+    }
+
+    /// Converts this page into a `text/csv` response, see [`PaginatedResponse::to_csv`].
+    ///
+    /// Pagination metadata is sent in the `X-Total-Count`, `X-Offset`
+    /// and `X-Limit` headers, see [`PaginatedResponse::pagination_headers`].
+    pub fn into_csv_response(self, headers: &[&str]) -> Response {
+        match self.to_csv(headers) {
+            Ok(body) => (
+                self.pagination_headers(),
+                [(
+                    header::CONTENT_TYPE,
+                    HeaderValue::from_static(CSV_CONTENT_TYPE),
+                )],
+                body,
+            )
+                .into_response(),
+            Err(e) => e.into_response(),
+        }
+    }
+
+    /// Converts this page into a JSON envelope or CSV response.
+    ///
+    /// `csv_headers` is the CSV header row, see [`PaginatedResponse::to_csv`].
+    pub fn into_response_as(self, format: ResponseFormat, csv_headers: &[&str]) -> Response {
+        match format {
+            ResponseFormat::Json => Json(self).into_response(),
+            ResponseFormat::Csv => self.into_csv_response(csv_headers),
+        }
+    }
+}
+
+/// Encodes a CSV header row.
+pub(crate) fn header_row<H: AsRef<str>>(headers: &[H]) -> Result<Vec<u8>, ApiError> {
+    let mut writer = csv::Writer::from_writer(Vec::new());
+    writer
+        .write_record(headers.iter().map(|h| h.as_ref()))
+        .map_err(csv_error)?;
+    into_bytes(writer)
+}
+
+/// Encodes an item as a CSV row, neutralising formulas.
+///
+/// The row must have one field per header. Field names written by `csv`
+/// (for structs) must match the headers.
+pub(crate) fn item_row<T, H>(item: &T, headers: &[H]) -> Result<Vec<u8>, ApiError>
+where
+    T: Serialize,
+    H: AsRef<str>,
+{
+    // csv can only serialize into a writer, so the record is read back to inspect its cells
+    let mut writer = csv::Writer::from_writer(Vec::new());
+    writer.serialize(item).map_err(csv_error)?;
+    let encoded = into_bytes(writer)?;
+
+    let mut reader = csv::ReaderBuilder::new()
+        .has_headers(false)
+        .from_reader(encoded.as_slice());
+    let mut records = reader.records();
+    let first = records.next().transpose().map_err(csv_error)?;
+    // csv writes a header row before struct records only
+    let (names, record) = match records.next().transpose().map_err(csv_error)? {
+        Some(row) => (first, row),
+        None => (None, first.unwrap_or_default()),
+    };
+    if let Some(names) = names {
+        if !names.iter().eq(headers.iter().map(|h| h.as_ref())) {
+            return Err(ApiError::Internal(format!(
+                "CSV headers do not match the record fields {:?}",
+                names.iter().collect::<Vec<_>>()
+            )));
+        }
+    }
+    if record.len() != headers.len() {
+        return Err(ApiError::Internal(format!(
+            "CSV record has {} fields, expected {}",
+            record.len(),
+            headers.len()
+        )));
+    }
+
+    let mut writer = csv::Writer::from_writer(Vec::new());
+    writer
+        .write_record(record.iter().map(neutralise_formula))
+        .map_err(csv_error)?;
+    into_bytes(writer)
+}
+
+fn neutralise_formula(cell: &str) -> String {
+    let risky = cell.starts_with(['=', '+', '-', '@', '\t', '\r']);
+    if risky && cell.parse::<f64>().is_err() {
+        format!("'{cell}")
+    } else {
+        cell.to_string()
+    }
+}
+
+fn into_bytes(writer: csv::Writer<Vec<u8>>) -> Result<Vec<u8>, ApiError> {
+    writer
+        .into_inner()
+        .map_err(|e| ApiError::Internal(format!("Failed to write CSV: {}", e.error())))
+}
+
+pub(crate) fn csv_error(e: csv::Error) -> ApiError {
+    ApiError::Internal(format!("Failed to serialize CSV record: {e}"))
+}
+
+#[cfg(feature = "stream")]
+mod stream {
+    use std::sync::Arc;
+
+    use axum::body::{Body, Bytes};
+    use axum::response::{IntoResponse, Response};
+    use futures_util::stream::{self, Stream, StreamExt};
+    use http::{HeaderMap, HeaderValue, header};
+    use serde::Serialize;
+
+    use super::{CSV_CONTENT_TYPE, header_row, item_row};
+    use crate::{ApiError, X_OFFSET, X_TOTAL_COUNT};
+
+    /// A `text/csv` response that serializes the items of a stream as they are produced.
+    ///
+    /// The header row is written first, even if the stream is empty. Rows are
+    /// encoded as in [`PaginatedResponse::to_csv`](crate::PaginatedResponse::to_csv).
+    /// If the stream yields an error, the response body is aborted, so clients
+    /// see a truncated transfer rather than a silently incomplete document.
+    /// Requires the `stream` feature.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// use bel7_axum::CsvStreamResponse;
+    ///
+    /// async fn export_users(State(db): State<PgPool>) -> CsvStreamResponse<impl Stream<...>> {
+    ///     let users = sqlx::query_as::<_, User>("SELECT id, name FROM users").fetch(&db);
+    ///     let users = users.map(|row| row.map_err(|e| ApiError::Internal(e.to_string())));
+    ///     CsvStreamResponse::new(users, &["id", "name"])
+    /// }
+    /// ```
+    #[derive(Debug)]
+    pub struct CsvStreamResponse<S> {
+        stream: S,
+        headers: Arc<[String]>,
+        offset: Option<u64>,
+        total: Option<u64>,
+    }
+
+    impl<S> CsvStreamResponse<S> {
+        /// Creates a CSV response from a stream of items and the header row.
+        ///
+        /// The header row can be built at runtime, e.g. from selected columns.
+        pub fn new<I>(stream: S, headers: I) -> Self
+        where
+            I: IntoIterator,
+            I::Item: AsRef<str>,
+        {
+            Self {
+                stream,
+                headers: headers.into_iter().map(|h| h.as_ref().to_owned()).collect(),
+                offset: None,
+                total: None,
+            }
+        }
+
+        /// Sets the `X-Offset` header.
+        pub fn with_offset(mut self, offset: u64) -> Self {
+            self.offset = Some(offset);
+            self
+        }
+
+        /// Sets the `X-Total-Count` header.
+        pub fn with_total(mut self, total: u64) -> Self {
+            self.total = Some(total);
+            self
+        }
+    }
+
+    impl<S, T, E> IntoResponse for CsvStreamResponse<S>
+    where
+        S: Stream<Item = Result<T, E>> + Send + 'static,
+        T: Serialize + Send + 'static,
+        E: Into<ApiError> + Send + 'static,
+    {
+        fn into_response(self) -> Response {
+            let mut headers = HeaderMap::new();
+            headers.insert(
+                header::CONTENT_TYPE,
+                HeaderValue::from_static(CSV_CONTENT_TYPE),
+            );
+            if let Some(total) = self.total {
+                headers.insert(X_TOTAL_COUNT, HeaderValue::from(total));
+            }
+            if let Some(offset) = self.offset {
+                headers.insert(X_OFFSET, HeaderValue::from(offset));
+            }
+
+            let header = match header_row(&self.headers) {
+                Ok(header) => header,
+                Err(e) => return e.into_response(),
+            };
+            let columns = self.headers;
+            let rows = self.stream.map(move |item| {
+                item.map_err(Into::into)
+                    .and_then(|item| item_row(&item, &columns))
+            });
+            let chunks = stream::once(std::future::ready(Ok(header)))
+                .chain(rows)
+                .map(|chunk| chunk.map(Bytes::from));
+
+            (headers, Body::from_stream(chunks)).into_response()
+        }
+    }
+}
+
+#[cfg(feature = "stream")]
+pub use stream::*;
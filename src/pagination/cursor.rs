@@ -0,0 +1,218 @@
+// Copyright (C) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Cursor-based pagination.
+//!
+//! Unlike offset/limit pagination, cursors point at a position in a sorted
+//! result set (usually the sort key of the last returned item), so pages stay
+//! stable under concurrent inserts and don't require scanning skipped rows.
+
+use std::convert::Infallible;
+
+use serde::{Deserialize, Serialize};
+
+/// A cursor-paginated response wrapper.
+///
+/// # Example
+///
+/// ```
+/// use bel7_axum::{CursorPaginatedResponse, CursorQuery};
+///
+/// struct Event {
+///     id: u64,
+/// }
+///
+/// let query = CursorQuery::default();
+/// let events = vec![Event { id: 11 }, Event { id: 12 }];
+/// let response = CursorPaginatedResponse::from_items(events, true, &query, |e| e.id);
+///
+/// assert_eq!(response.next_cursor.as_deref(), Some("12"));
+/// assert_eq!(response.prev_cursor, None);
+/// assert!(response.has_more);
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct CursorPaginatedResponse<T> {
+    /// This page's items.
+    pub data: Vec<T>,
+
+    /// Cursor for the page after this one, to be sent as `cursor`.
+    /// Omitted from JSON if None.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub next_cursor: Option<String>,
+
+    /// Cursor for the page before this one, to be sent as `before`.
+    /// Omitted from JSON if None.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub prev_cursor: Option<String>,
+
+    /// Whether there are more items after this page.
+    pub has_more: bool,
+}
+
+impl<T> CursorPaginatedResponse<T> {
+    /// Creates a new cursor-paginated response.
+    pub fn new(
+        data: Vec<T>,
+        next_cursor: Option<String>,
+        prev_cursor: Option<String>,
+        has_more: bool,
+    ) -> Self {
+        Self {
+            data,
+            next_cursor,
+            prev_cursor,
+            has_more,
+        }
+    }
+
+    /// Creates a response, deriving cursors from the items' sort keys.
+    ///
+    /// `data` must be in sort order. `has_more` tells whether there are more
+    /// items in the direction of travel: after this page for forward requests,
+    /// before it for backward ones ([`CursorQuery::before`]).
+    ///
+    /// The next cursor is the key of the last item, the previous cursor the
+    /// key of the first item. Clients send them back as `cursor` and `before`
+    /// respectively. A forward page only has a previous cursor if the request
+    /// carried a cursor, i.e. if it is not the first page. A backward page
+    /// always has a next cursor, since the request came from a later page.
+    pub fn from_items<K, F>(data: Vec<T>, has_more: bool, query: &CursorQuery, key: F) -> Self
+    where
+        K: ToString,
+        F: Fn(&T) -> K,
+    {
+        let encode = |item: &T| Ok::<_, Infallible>(key(item).to_string());
+        match Self::try_from_items(data, has_more, query, encode) {
+            Ok(response) => response,
+            Err(never) => match never {},
+        }
+    }
+
+    /// Like [`CursorPaginatedResponse::from_items`], with a fallible cursor encoder.
+    pub(crate) fn try_from_items<E, F>(
+        data: Vec<T>,
+        has_more: bool,
+        query: &CursorQuery,
+        encode: F,
+    ) -> Result<Self, E>
+    where
+        F: Fn(&T) -> Result<String, E>,
+    {
+        let first = || data.first().map(&encode).transpose();
+        let last = || data.last().map(&encode).transpose();
+
+        let (next_cursor, prev_cursor, has_more) = if query.is_backward() {
+            let next_cursor = last()?;
+            let has_next = next_cursor.is_some();
+            let prev_cursor = if has_more { first()? } else { None };
+            (next_cursor, prev_cursor, has_next)
+        } else {
+            let next_cursor = if has_more { last()? } else { None };
+            let prev_cursor = if query.cursor.is_some() {
+                first()?
+            } else {
+                None
+            };
+            (next_cursor, prev_cursor, has_more)
+        };
+
+        Ok(Self {
+            data,
+            next_cursor,
+            prev_cursor,
+            has_more,
+        })
+    }
+
+    /// Creates a response for a single page with all items.
+    pub fn single_page(data: Vec<T>) -> Self {
+        Self {
+            data,
+            next_cursor: None,
+            prev_cursor: None,
+            has_more: false,
+        }
+    }
+
+    /// Maps the data items using the provided function.
+    pub fn map<U, F>(self, f: F) -> CursorPaginatedResponse<U>
+    where
+        F: FnMut(T) -> U,
+    {
+        CursorPaginatedResponse {
+            data: self.data.into_iter().map(f).collect(),
+            next_cursor: self.next_cursor,
+            prev_cursor: self.prev_cursor,
+            has_more: self.has_more,
+        }
+    }
+}
+
+/// Query parameters for cursor-based pagination.
+/// Meant to be used with [`axum::extract::Query`].
+///
+/// `cursor` requests the page after a position (a response's `next_cursor`),
+/// `before` the page before it (a response's `prev_cursor`). If both are set,
+/// `before` wins.
+///
+/// A backward page is fetched in reverse: with the reversed sort order
+/// ([`SortSpec::reversed`](crate::SortSpec::reversed)) and its keyset predicate.
+/// [`CursorPaginatedResponse::from_probe`] puts the rows back into sort order.
+///
+/// # Example
+///
+/// ```ignore
+/// use axum::extract::Query;
+/// use bel7_axum::{CursorPaginatedResponse, CursorQuery};
+///
+/// async fn list_events(Query(query): Query<CursorQuery>) -> impl IntoResponse {
+///     let limit = query.effective_limit(100);
+///     let position: Option<u64> = query.position().and_then(|c| c.parse().ok());
+///     // fetch `limit + 1` rows with `id > position ORDER BY id`,
+///     // or `id < position ORDER BY id DESC` if `query.is_backward()`
+///     // ...
+/// }
+/// ```
+#[derive(Debug, Clone, Default, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::IntoParams))]
+#[cfg_attr(feature = "openapi", into_params(parameter_in = Query))]
+pub struct CursorQuery {
+    /// Cursor of the item to continue after, from a response's `next_cursor`.
+    pub cursor: Option<String>,
+
+    /// Cursor of the item to continue before, from a response's `prev_cursor`.
+    #[serde(default)]
+    pub before: Option<String>,
+
+    /// Maximum number of items to return.
+    pub limit: Option<u64>,
+}
+
+impl CursorQuery {
+    /// Returns the effective limit, clamped to a maximum value if needed.
+    pub fn effective_limit(&self, max: u64) -> u64 {
+        self.limit.unwrap_or(max).min(max)
+    }
+
+    /// Returns `true` if this query requests the page before a cursor.
+    pub fn is_backward(&self) -> bool {
+        self.before.is_some()
+    }
+
+    /// Returns the cursor to page from: `before` if set, `cursor` otherwise.
+    pub fn position(&self) -> Option<&str> {
+        self.before.as_deref().or(self.cursor.as_deref())
+    }
+}
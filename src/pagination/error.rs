@@ -0,0 +1,96 @@
+// Copyright (C) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Pagination errors.
+
+use thiserror::Error;
+
+use super::TotalKind;
+use crate::{ApiError, IntoApiError};
+
+/// Errors produced when converting or validating pagination parameters.
+///
+/// Invalid client input converts into [`ApiError::BadRequest`], inconsistent
+/// page data (e.g. more items than the limit) into [`ApiError::Internal`].
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum PaginationError {
+    /// The limit (page size) is missing or zero.
+    #[error("limit must be set and greater than zero")]
+    MissingLimit,
+
+    /// The offset does not fall on a page boundary.
+    #[error("offset {offset} is not a multiple of the page size {limit}")]
+    UnalignedOffset {
+        /// The offending offset.
+        offset: u64,
+        /// The page size.
+        limit: u64,
+    },
+
+    /// The offset plus the number of returned items does not fit into a `u64`.
+    #[error("offset {offset} plus {returned} returned items overflows")]
+    Overflow {
+        /// The offending offset.
+        offset: u64,
+        /// The number of returned items.
+        returned: u64,
+    },
+
+    /// A page has more items than its limit.
+    #[error("page has {returned} items, more than the limit of {limit}")]
+    PageTooLarge {
+        /// The number of returned items.
+        returned: u64,
+        /// The page size.
+        limit: u64,
+    },
+
+    /// The offset plus the number of returned items exceeds the total.
+    #[error("offset {offset} plus {returned} returned items exceeds the total of {total}")]
+    ExceedsTotal {
+        /// The page offset.
+        offset: u64,
+        /// The number of returned items.
+        returned: u64,
+        /// The total number of items.
+        total: u64,
+    },
+
+    /// The offset is past the last item.
+    #[error("offset {offset} is past the end of the collection of {total} items")]
+    OffsetPastEnd {
+        /// The offending offset.
+        offset: u64,
+        /// The total number of items.
+        total: u64,
+    },
+
+    /// Page numbers were requested for a response without an exact total.
+    #[error("page numbers require an exact total, the total is {kind:?}")]
+    InexactTotal {
+        /// How the total was obtained.
+        kind: TotalKind,
+    },
+}
+
+impl IntoApiError for PaginationError {
+    fn into_api_error(self) -> ApiError {
+        match self {
+            PaginationError::PageTooLarge { .. }
+            | PaginationError::ExceedsTotal { .. }
+            | PaginationError::InexactTotal { .. } => ApiError::Internal(self.to_string()),
+            _ => ApiError::BadRequest(self.to_string()),
+        }
+    }
+}
@@ -0,0 +1,136 @@
+// Copyright (C) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Pagination extractor with built-in bounds.
+
+use axum::extract::FromRequestParts;
+use http::request::Parts;
+
+use super::{PaginatedResponse, PaginationQuery};
+use crate::ApiError;
+
+/// Extracts and validates `limit` and `offset` query parameters.
+///
+/// Unlike `Query<PaginationQuery>`, malformed values are rejected with
+/// [`ApiError::BadRequest`] in the standard JSON error body. A `limit` of zero
+/// or above `MAX_LIMIT` is rejected, or clamped into `1..=MAX_LIMIT` when `CLAMP`
+/// is true (see [`ClampedPagination`]). A missing `limit` defaults to `DEFAULT_LIMIT`.
+///
+/// # Example
+///
+/// ```ignore
+/// use bel7_axum::{PaginatedResponse, Pagination};
+///
+/// // ?limit=500 is rejected, ?limit is 25 by default
+/// async fn list_items(pagination: Pagination<25, 200>) -> PaginatedResponse<Item> {
+///     let items = repo.list(pagination.limit, pagination.offset).await;
+///     let total = repo.count().await;
+///     pagination.response(items, total)
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Pagination<
+    const DEFAULT_LIMIT: u64 = 20,
+    const MAX_LIMIT: u64 = 100,
+    const CLAMP: bool = false,
+> {
+    /// Maximum number of items to return, within `1..=MAX_LIMIT`.
+    pub limit: u64,
+
+    /// Number of items to skip.
+    pub offset: u64,
+}
+
+/// A [`Pagination`] extractor that clamps out-of-range limits instead of rejecting them.
+pub type ClampedPagination<const DEFAULT_LIMIT: u64 = 20, const MAX_LIMIT: u64 = 100> =
+    Pagination<DEFAULT_LIMIT, MAX_LIMIT, true>;
+
+impl<const DEFAULT_LIMIT: u64, const MAX_LIMIT: u64, const CLAMP: bool>
+    Pagination<DEFAULT_LIMIT, MAX_LIMIT, CLAMP>
+{
+    /// Validates a query string, e.g. `limit=10&offset=20`.
+    pub fn from_query(query: &str) -> Result<Self, ApiError> {
+        let mut limit = None;
+        let mut offset = None;
+        for (key, value) in form_urlencoded::parse(query.as_bytes()) {
+            match key.as_ref() {
+                "limit" => limit = Some(parse_u64_param("limit", &value)?),
+                "offset" => offset = Some(parse_u64_param("offset", &value)?),
+                _ => {}
+            }
+        }
+
+        let max = MAX_LIMIT.max(1);
+        let limit = match limit {
+            None => DEFAULT_LIMIT.clamp(1, max),
+            Some(limit) if CLAMP => limit.clamp(1, max),
+            Some(0) => {
+                return Err(ApiError::BadRequest(
+                    "limit must be greater than zero".into(),
+                ));
+            }
+            Some(limit) if limit > max => {
+                return Err(ApiError::BadRequest(format!("limit must not exceed {max}")));
+            }
+            Some(limit) => limit,
+        };
+
+        Ok(Self {
+            limit,
+            offset: offset.unwrap_or(0),
+        })
+    }
+
+    /// Returns the equivalent [`PaginationQuery`].
+    pub fn query(&self) -> PaginationQuery {
+        PaginationQuery {
+            limit: Some(self.limit),
+            offset: Some(self.offset),
+        }
+    }
+
+    /// Creates a [`PaginatedResponse`] for this page.
+    pub fn response<T>(&self, data: Vec<T>, total: u64) -> PaginatedResponse<T> {
+        PaginatedResponse::new(data, total, Some(self.limit), self.offset)
+    }
+}
+
+impl<const DEFAULT_LIMIT: u64, const MAX_LIMIT: u64, const CLAMP: bool>
+    From<Pagination<DEFAULT_LIMIT, MAX_LIMIT, CLAMP>> for PaginationQuery
+{
+    fn from(pagination: Pagination<DEFAULT_LIMIT, MAX_LIMIT, CLAMP>) -> Self {
+        pagination.query()
+    }
+}
+
+impl<S, const DEFAULT_LIMIT: u64, const MAX_LIMIT: u64, const CLAMP: bool> FromRequestParts<S>
+    for Pagination<DEFAULT_LIMIT, MAX_LIMIT, CLAMP>
+where
+    S: Send + Sync,
+{
+    type Rejection = ApiError;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        Self::from_query(parts.uri.query().unwrap_or(""))
+    }
+}
+
+/// Parses a non-negative integer query parameter.
+pub(crate) fn parse_u64_param(name: &str, value: &str) -> Result<u64, ApiError> {
+    value.parse().map_err(|_| {
+        ApiError::BadRequest(format!(
+            "{name} must be a non-negative integer, got '{value}'"
+        ))
+    })
+}
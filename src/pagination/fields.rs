@@ -0,0 +1,270 @@
+// Copyright (C) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Configurable field names of the paginated response envelope.
+
+use serde::ser::{Serialize, SerializeMap, Serializer};
+
+use super::PaginatedResponse;
+
+/// Field names used when serializing a [`PaginatedResponse`] envelope.
+///
+/// Omitted fields are left out of the envelope. The `next` and `previous` page
+/// URL fields are opt-in, so that they cannot clash with names chosen for other
+/// fields. Methods that set a name panic if another field already has that
+/// name. In a `const`, such as the one below, this fails compilation.
+///
+/// # Example
+///
+/// ```
+/// use bel7_axum::{EnvelopeFields, PaginatedResponse};
+///
+/// const LEGACY: EnvelopeFields = EnvelopeFields::new()
+///     .data("items")
+///     .total("count")
+///     .limit("page_size")
+///     .has_more("next")
+///     .without_offset()
+///     .without_total_kind();
+///
+/// let page = PaginatedResponse::new(vec![1, 2], 5, Some(2), 0);
+/// let json = serde_json::to_string(&page.with_fields(LEGACY)).unwrap();
+///
+/// assert_eq!(json, r#"{"items":[1,2],"count":5,"page_size":2,"next":true}"#);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct EnvelopeFields {
+    /// Name of the items field.
+    data: &'static str,
+    /// Name of the total field.
+    total: Option<&'static str>,
+    /// Name of the total kind field. Omitted when the total is exact.
+    total_kind: Option<&'static str>,
+    /// Name of the limit field. Omitted when the limit is not set.
+    limit: Option<&'static str>,
+    /// Name of the offset field.
+    offset: Option<&'static str>,
+    /// Name of the has-more field.
+    has_more: Option<&'static str>,
+    /// Name of the next page URL field, if included. Omitted when there is no URL.
+    next: Option<&'static str>,
+    /// Name of the previous page URL field, if included. Omitted when there is no URL.
+    previous: Option<&'static str>,
+}
+
+impl Default for EnvelopeFields {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl EnvelopeFields {
+    /// Returns the standard field names, without the page URL fields.
+    pub const fn new() -> Self {
+        Self {
+            data: "data",
+            total: Some("total"),
+            total_kind: Some("total_kind"),
+            limit: Some("limit"),
+            offset: Some("offset"),
+            has_more: Some("has_more"),
+            next: None,
+            previous: None,
+        }
+    }
+
+    /// Renames the items field.
+    pub const fn data(mut self, name: &'static str) -> Self {
+        self.data = name;
+        self.assert_unique_names()
+    }
+
+    /// Renames the total field.
+    pub const fn total(mut self, name: &'static str) -> Self {
+        self.total = Some(name);
+        self.assert_unique_names()
+    }
+
+    /// Renames the total kind field.
+    pub const fn total_kind(mut self, name: &'static str) -> Self {
+        self.total_kind = Some(name);
+        self.assert_unique_names()
+    }
+
+    /// Renames the limit field.
+    pub const fn limit(mut self, name: &'static str) -> Self {
+        self.limit = Some(name);
+        self.assert_unique_names()
+    }
+
+    /// Renames the offset field.
+    pub const fn offset(mut self, name: &'static str) -> Self {
+        self.offset = Some(name);
+        self.assert_unique_names()
+    }
+
+    /// Renames the has-more field.
+    pub const fn has_more(mut self, name: &'static str) -> Self {
+        self.has_more = Some(name);
+        self.assert_unique_names()
+    }
+
+    /// Includes the next page URL under the given name.
+    pub const fn next(mut self, name: &'static str) -> Self {
+        self.next = Some(name);
+        self.assert_unique_names()
+    }
+
+    /// Includes the previous page URL under the given name.
+    pub const fn previous(mut self, name: &'static str) -> Self {
+        self.previous = Some(name);
+        self.assert_unique_names()
+    }
+
+    /// Omits the total field.
+    pub const fn without_total(mut self) -> Self {
+        self.total = None;
+        self
+    }
+
+    /// Omits the total kind field.
+    pub const fn without_total_kind(mut self) -> Self {
+        self.total_kind = None;
+        self
+    }
+
+    /// Omits the limit field.
+    pub const fn without_limit(mut self) -> Self {
+        self.limit = None;
+        self
+    }
+
+    /// Omits the offset field.
+    pub const fn without_offset(mut self) -> Self {
+        self.offset = None;
+        self
+    }
+
+    /// Omits the has-more field.
+    pub const fn without_has_more(mut self) -> Self {
+        self.has_more = None;
+        self
+    }
+
+    /// Includes the page URLs as `next` and `previous`, like the default envelope.
+    pub const fn with_page_urls(self) -> Self {
+        self.next("next").previous("previous")
+    }
+
+    /// Omits the next and previous page URL fields.
+    pub const fn without_page_urls(mut self) -> Self {
+        self.next = None;
+        self.previous = None;
+        self
+    }
+
+    const fn assert_unique_names(self) -> Self {
+        let names = [
+            Some(self.data),
+            self.total,
+            self.total_kind,
+            self.limit,
+            self.offset,
+            self.has_more,
+            self.next,
+            self.previous,
+        ];
+        let mut i = 0;
+        while i < names.len() {
+            let mut j = 0;
+            while j < i {
+                if let (Some(a), Some(b)) = (names[i], names[j]) {
+                    if str_eq(a, b) {
+                        panic!("duplicate envelope field name");
+                    }
+                }
+                j += 1;
+            }
+            i += 1;
+        }
+        self
+    }
+}
+
+// `str` equality is not available in `const fn`
+const fn str_eq(a: &str, b: &str) -> bool {
+    let (a, b) = (a.as_bytes(), b.as_bytes());
+    if a.len() != b.len() {
+        return false;
+    }
+    let mut i = 0;
+    while i < a.len() {
+        if a[i] != b[i] {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+/// A [`PaginatedResponse`] that serializes with custom field names.
+///
+/// Created with [`PaginatedResponse::with_fields`].
+#[derive(Debug, Clone, Copy)]
+pub struct NamedEnvelope<'a, T> {
+    response: &'a PaginatedResponse<T>,
+    fields: EnvelopeFields,
+}
+
+impl<T> PaginatedResponse<T> {
+    /// Returns a view of this response that serializes with the given field names.
+    pub fn with_fields(&self, fields: EnvelopeFields) -> NamedEnvelope<'_, T> {
+        NamedEnvelope {
+            response: self,
+            fields,
+        }
+    }
+}
+
+impl<T: Serialize> Serialize for NamedEnvelope<'_, T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let r = self.response;
+        let f = &self.fields;
+
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry(f.data, &r.data)?;
+        if let Some(name) = f.total {
+            map.serialize_entry(name, &r.total)?;
+        }
+        if let (Some(name), false) = (f.total_kind, r.total_kind.is_exact()) {
+            map.serialize_entry(name, &r.total_kind)?;
+        }
+        if let (Some(name), Some(limit)) = (f.limit, r.limit) {
+            map.serialize_entry(name, &limit)?;
+        }
+        if let Some(name) = f.offset {
+            map.serialize_entry(name, &r.offset)?;
+        }
+        if let Some(name) = f.has_more {
+            map.serialize_entry(name, &r.has_more)?;
+        }
+        if let (Some(name), Some(url)) = (f.next, &r.next) {
+            map.serialize_entry(name, url)?;
+        }
+        if let (Some(name), Some(url)) = (f.previous, &r.previous) {
+            map.serialize_entry(name, url)?;
+        }
+        map.end()
+    }
+}
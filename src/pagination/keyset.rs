@@ -0,0 +1,269 @@
+// Copyright (C) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Keyset (seek) pagination.
+//!
+//! Instead of skipping `offset` rows, keyset pagination continues right
+//! after the sort key of the last row of the previous page, e.g.
+//! `WHERE (created_at, id) > (?, ?) ORDER BY created_at, id LIMIT ?`.
+//! The sort specification should end with a unique column (such as the
+//! primary key) so that keys are never tied.
+
+#[cfg(feature = "cursor-codec")]
+use serde::Serialize;
+
+#[cfg(feature = "cursor-codec")]
+use super::CursorCodec;
+use super::{CursorPaginatedResponse, CursorQuery, SortDirection, SortSpec};
+use crate::ApiError;
+
+/// Bind parameter placeholder syntax of the target database.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PlaceholderStyle {
+    /// `?` placeholders (SQLite, MySQL).
+    QuestionMark,
+    /// `$1`, `$2`, ... placeholders (PostgreSQL), starting at the given index.
+    Numbered(usize),
+}
+
+/// An SQL predicate selecting the rows after a key, with its bind parameters.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeysetPredicate<V> {
+    /// The predicate, e.g. `(created_at, id) > (?, ?)`.
+    pub sql: String,
+
+    /// Values to bind, in placeholder order.
+    pub params: Vec<V>,
+}
+
+impl SortSpec {
+    /// Builds the predicate that selects rows coming after `key` in this sort order.
+    ///
+    /// `key` holds the last row's values of the sort fields, in the same order.
+    /// When all fields are sorted in the same direction, a row value comparison
+    /// is produced. Mixed directions expand into an equivalent `OR` chain.
+    ///
+    /// To fetch the page *before* a key, use the predicate and `ORDER BY` of
+    /// [`SortSpec::reversed`] and reverse the fetched rows.
+    ///
+    /// Returns [`ApiError::BadRequest`] if the key does not have one value per sort field,
+    /// and [`ApiError::Internal`] if this sort specification is empty: keyset pagination
+    /// needs at least one sort field, which is a server configuration concern.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bel7_axum::{PlaceholderStyle, SortSpec};
+    ///
+    /// let spec = SortSpec::parse("-created_at,id", &["created_at", "id"]).unwrap();
+    /// let predicate = spec
+    ///     .keyset_predicate(&["2025-06-01", "42"], PlaceholderStyle::Numbered(1))
+    ///     .unwrap();
+    ///
+    /// assert_eq!(
+    ///     predicate.sql,
+    ///     "(created_at < $1 OR (created_at = $2 AND id > $3))"
+    /// );
+    /// assert_eq!(predicate.params, vec!["2025-06-01", "2025-06-01", "42"]);
+    /// ```
+    pub fn keyset_predicate<V: Clone>(
+        &self,
+        key: &[V],
+        style: PlaceholderStyle,
+    ) -> Result<KeysetPredicate<V>, ApiError> {
+        if self.is_empty() {
+            return Err(ApiError::Internal(
+                "keyset pagination requires at least one sort field".into(),
+            ));
+        }
+        if key.len() != self.fields.len() {
+            return Err(ApiError::BadRequest("Invalid cursor".into()));
+        }
+
+        let mut placeholders = Placeholders::new(style);
+        let direction = self.fields[0].direction;
+        let uniform = self.fields.iter().all(|f| f.direction == direction);
+
+        if uniform {
+            let columns: Vec<&str> = self.fields.iter().map(|f| f.name.as_str()).collect();
+            let values: Vec<String> = key.iter().map(|_| placeholders.next()).collect();
+            let sql = if columns.len() == 1 {
+                format!("{} {} {}", columns[0], comparison(direction), values[0])
+            } else {
+                format!(
+                    "({}) {} ({})",
+                    columns.join(", "),
+                    comparison(direction),
+                    values.join(", ")
+                )
+            };
+            return Ok(KeysetPredicate {
+                sql,
+                params: key.to_vec(),
+            });
+        }
+
+        // (a > ?) OR (a = ? AND b < ?) OR (a = ? AND b = ? AND c > ?) ...
+        let mut branches = Vec::with_capacity(self.fields.len());
+        let mut params = Vec::new();
+        for (i, field) in self.fields.iter().enumerate() {
+            let mut terms = Vec::with_capacity(i + 1);
+            for (prefix, value) in self.fields[..i].iter().zip(key) {
+                terms.push(format!("{} = {}", prefix.name, placeholders.next()));
+                params.push(value.clone());
+            }
+            terms.push(format!(
+                "{} {} {}",
+                field.name,
+                comparison(field.direction),
+                placeholders.next()
+            ));
+            params.push(key[i].clone());
+
+            if terms.len() == 1 {
+                branches.push(terms.remove(0));
+            } else {
+                branches.push(format!("({})", terms.join(" AND ")));
+            }
+        }
+
+        Ok(KeysetPredicate {
+            sql: format!("({})", branches.join(" OR ")),
+            params,
+        })
+    }
+}
+
+impl<T> CursorPaginatedResponse<T> {
+    /// Creates a response from rows fetched with `LIMIT limit + 1`.
+    ///
+    /// The extra row, if present, only signals that there are more rows:
+    /// it is dropped and `has_more` is set. Cursors are derived from the
+    /// remaining rows' keys as in [`CursorPaginatedResponse::from_items`].
+    ///
+    /// For backward queries ([`CursorQuery::before`]), `rows` are expected
+    /// in reverse sort order and are put back into sort order.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bel7_axum::{CursorPaginatedResponse, CursorQuery};
+    ///
+    /// // fetched with LIMIT 3 for a page size of 2
+    /// let rows = vec![10, 11, 12];
+    /// let page = CursorPaginatedResponse::from_probe(rows, 2, &CursorQuery::default(), |id| *id);
+    ///
+    /// assert_eq!(page.data, vec![10, 11]);
+    /// assert!(page.has_more);
+    /// assert_eq!(page.next_cursor.as_deref(), Some("11"));
+    /// ```
+    pub fn from_probe<K, F>(mut rows: Vec<T>, limit: u64, query: &CursorQuery, key: F) -> Self
+    where
+        K: ToString,
+        F: Fn(&T) -> K,
+    {
+        let has_more = probe(&mut rows, limit, query);
+        Self::from_items(rows, has_more, query, key)
+    }
+
+    /// Like [`CursorPaginatedResponse::from_probe`], with cursors encoded by a [`CursorCodec`].
+    ///
+    /// Keys can be of any `Serialize` type, e.g. the values of all sort fields.
+    /// Requires the `cursor-codec` feature.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bel7_axum::{CursorCodec, CursorPaginatedResponse, CursorQuery, PlaceholderStyle, SortSpec};
+    ///
+    /// let codec = CursorCodec::signed("secret");
+    /// let spec = SortSpec::parse("-score,id", &["score", "id"]).unwrap();
+    ///
+    /// // (score, id) rows fetched with LIMIT 3 for a page size of 2
+    /// let rows = vec![(90, 4), (85, 7), (85, 9)];
+    /// let query = CursorQuery::default();
+    /// let page = CursorPaginatedResponse::from_probe_encoded(rows, 2, &query, &codec, |row| {
+    ///     vec![row.0, row.1]
+    /// })
+    /// .unwrap();
+    ///
+    /// // the client sends next_cursor back, the key is decoded into a predicate
+    /// let query = CursorQuery { cursor: page.next_cursor, ..Default::default() };
+    /// let key: Vec<i64> = codec.decode_query(&query).unwrap().unwrap();
+    /// let predicate = spec.keyset_predicate(&key, PlaceholderStyle::QuestionMark).unwrap();
+    ///
+    /// assert_eq!(predicate.sql, "(score < ? OR (score = ? AND id > ?))");
+    /// assert_eq!(predicate.params, vec![85, 85, 7]);
+    /// ```
+    #[cfg(feature = "cursor-codec")]
+    pub fn from_probe_encoded<K, F>(
+        mut rows: Vec<T>,
+        limit: u64,
+        query: &CursorQuery,
+        codec: &CursorCodec,
+        key: F,
+    ) -> Result<Self, ApiError>
+    where
+        K: Serialize,
+        F: Fn(&T) -> K,
+    {
+        let has_more = probe(&mut rows, limit, query);
+        Self::try_from_items(rows, has_more, query, |item| codec.encode(&key(item)))
+    }
+}
+
+/// Drops the probe row, if any, and puts backward rows into sort order.
+/// Returns whether there are more rows.
+fn probe<T>(rows: &mut Vec<T>, limit: u64, query: &CursorQuery) -> bool {
+    let limit = usize::try_from(limit).unwrap_or(usize::MAX);
+    let has_more = rows.len() > limit;
+    rows.truncate(limit);
+    if query.is_backward() {
+        rows.reverse();
+    }
+    has_more
+}
+
+fn comparison(direction: SortDirection) -> &'static str {
+    match direction {
+        SortDirection::Asc => ">",
+        SortDirection::Desc => "<",
+    }
+}
+
+struct Placeholders {
+    style: PlaceholderStyle,
+    index: usize,
+}
+
+impl Placeholders {
+    fn new(style: PlaceholderStyle) -> Self {
+        let index = match style {
+            PlaceholderStyle::QuestionMark => 0,
+            PlaceholderStyle::Numbered(start) => start,
+        };
+        Self { style, index }
+    }
+
+    fn next(&mut self) -> String {
+        match self.style {
+            PlaceholderStyle::QuestionMark => "?".to_string(),
+            PlaceholderStyle::Numbered(_) => {
+                let placeholder = format!("${}", self.index);
+                self.index += 1;
+                placeholder
+            }
+        }
+    }
+}
@@ -0,0 +1,187 @@
+// Copyright (C) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! RFC 8288 `Link` headers for paginated responses.
+
+use axum::response::{IntoResponse, Response};
+use http::{HeaderValue, Uri, header};
+use serde::Serialize;
+
+use super::PaginatedResponse;
+
+/// URLs of the pages around a [`PaginatedResponse`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PageLinks {
+    /// The first page.
+    pub first: Option<String>,
+    /// The page before the current one.
+    pub prev: Option<String>,
+    /// The page after the current one.
+    pub next: Option<String>,
+    /// The last page.
+    pub last: Option<String>,
+}
+
+impl PageLinks {
+    /// Formats the links as an RFC 8288 `Link` header value.
+    ///
+    /// Returns `None` if there are no links.
+    pub fn to_header_value(&self) -> Option<HeaderValue> {
+        let links: Vec<String> = [
+            (&self.first, "first"),
+            (&self.prev, "prev"),
+            (&self.next, "next"),
+            (&self.last, "last"),
+        ]
+        .into_iter()
+        .filter_map(|(url, rel)| url.as_ref().map(|url| format!("<{url}>; rel=\"{rel}\"")))
+        .collect();
+
+        if links.is_empty() {
+            return None;
+        }
+        HeaderValue::from_str(&links.join(", ")).ok()
+    }
+}
+
+impl<T> PaginatedResponse<T> {
+    /// Computes the `first`, `prev`, `next` and `last` page URLs.
+    ///
+    /// URLs are derived from the request URI: the `limit` and `offset`
+    /// query parameters are replaced, all other parameters are preserved.
+    /// No links are produced when `limit` is not set, and no `last`
+    /// link is produced when the total is not exact.
+    pub fn page_links(&self, uri: &Uri) -> PageLinks {
+        let limit = match self.limit {
+            Some(limit) if limit > 0 => limit,
+            _ => return PageLinks::default(),
+        };
+        let last_offset = match self.total {
+            0 => 0,
+            total => ((total - 1) / limit) * limit,
+        };
+
+        PageLinks {
+            first: Some(uri_with_pagination(uri, limit, 0)),
+            prev: (self.offset > 0)
+                .then(|| uri_with_pagination(uri, limit, self.offset.saturating_sub(limit))),
+            next: self
+                .has_more
+                .then(|| uri_with_pagination(uri, limit, self.offset.saturating_add(limit))),
+            last: self
+                .is_total_exact()
+                .then(|| uri_with_pagination(uri, limit, last_offset)),
+        }
+    }
+
+    /// Sets the `next` and `previous` URLs of this page.
+    ///
+    /// The URLs are computed as in [`PaginatedResponse::page_links`] and are
+    /// absolute only if the request URI is. Use
+    /// [`PaginatedResponse::with_absolute_page_urls`] for path-only URIs,
+    /// which is what most handlers receive.
+    pub fn with_page_urls(mut self, uri: &Uri) -> Self {
+        let links = self.page_links(uri);
+        self.next = links.next;
+        self.previous = links.prev;
+        self
+    }
+
+    /// Sets absolute `next` and `previous` URLs of this page.
+    ///
+    /// `base_url` (e.g. `https://api.example.com`) is prepended to the
+    /// request URI's path and query; the URI's own scheme and authority,
+    /// if any, are ignored.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bel7_axum::PaginatedResponse;
+    /// use http::Uri;
+    ///
+    /// let uri: Uri = "/items?sort=name&limit=10&offset=10".parse().unwrap();
+    /// let page = PaginatedResponse::new(vec![1; 10], 35, Some(10), 10)
+    ///     .with_absolute_page_urls("https://api.example.com", &uri);
+    ///
+    /// assert_eq!(
+    ///     page.next.as_deref(),
+    ///     Some("https://api.example.com/items?sort=name&limit=10&offset=20")
+    /// );
+    /// assert_eq!(
+    ///     page.previous.as_deref(),
+    ///     Some("https://api.example.com/items?sort=name&limit=10&offset=0")
+    /// );
+    /// ```
+    pub fn with_absolute_page_urls(self, base_url: &str, uri: &Uri) -> Self {
+        let mut page = match uri.path_and_query() {
+            Some(path_and_query) => match Uri::try_from(path_and_query.as_str()) {
+                Ok(relative) => self.with_page_urls(&relative),
+                Err(_) => self.with_page_urls(uri),
+            },
+            None => self.with_page_urls(uri),
+        };
+        let base_url = base_url.trim_end_matches('/');
+        page.next = page.next.map(|url| format!("{base_url}{url}"));
+        page.previous = page.previous.map(|url| format!("{base_url}{url}"));
+        page
+    }
+
+    /// Returns the RFC 8288 `Link` header value for this page.
+    pub fn link_header(&self, uri: &Uri) -> Option<HeaderValue> {
+        self.page_links(uri).to_header_value()
+    }
+
+    /// Converts this page into a JSON response with a `Link` header.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// use axum::extract::{OriginalUri, Query};
+    /// use bel7_axum::{PaginatedResponse, PaginationQuery};
+    ///
+    /// async fn list_items(OriginalUri(uri): OriginalUri, Query(q): Query<PaginationQuery>) -> Response {
+    ///     let page = fetch_page(&q).await;
+    ///     page.into_response_with_links(&uri)
+    /// }
+    /// ```
+    pub fn into_response_with_links(self, uri: &Uri) -> Response
+    where
+        T: Serialize,
+    {
+        let link = self.link_header(uri);
+        let mut response = self.into_response();
+        if let Some(link) = link {
+            response.headers_mut().insert(header::LINK, link);
+        }
+        response
+    }
+}
+
+/// Returns the URI with its `limit` and `offset` query parameters replaced.
+pub(crate) fn uri_with_pagination(uri: &Uri, limit: u64, offset: u64) -> String {
+    let mut query = form_urlencoded::Serializer::new(String::new());
+    for (key, value) in form_urlencoded::parse(uri.query().unwrap_or("").as_bytes()) {
+        if key != "limit" && key != "offset" {
+            query.append_pair(&key, &value);
+        }
+    }
+    query.append_pair("limit", &limit.to_string());
+    query.append_pair("offset", &offset.to_string());
+
+    let prefix = match (uri.scheme_str(), uri.authority()) {
+        (Some(scheme), Some(authority)) => format!("{scheme}://{authority}"),
+        _ => String::new(),
+    };
+    format!("{prefix}{}?{}", uri.path(), query.finish())
+}
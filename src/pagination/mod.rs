@@ -0,0 +1,82 @@
+// Copyright (C) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Pagination utilities for API responses.
+//!
+//! This module provides:
+//! - [`PaginatedResponse`] and [`PaginationQuery`] - Offset/limit-based pagination,
+//!   with exact, estimated or unknown totals ([`TotalKind`])
+//! - [`Pagination`] - Extractor for `limit`/`offset` with bounds and [`ApiError`](crate::ApiError) rejections
+//! - [`SortSpec`] and [`ListQuery`] - Sort and filter parameters of list endpoints
+//! - [`PagedResponse`] and [`PageQuery`] - Page number-based pagination
+//! - [`CursorPaginatedResponse`] and [`CursorQuery`] - Cursor-based pagination
+//! - [`CursorCodec`] - Opaque, signed cursors (requires `cursor-codec` feature)
+//! - [`KeysetPredicate`] - Keyset (seek) pagination predicates for a [`SortSpec`]
+//! - [`Paginator`] and [`CursorPaginator`] - Paginated data sources that can be
+//!   streamed page by page (requires `stream` feature)
+//! - [`walk_pages`] - Client-side auto-pagination (requires `stream` feature)
+//! - [`StreamingResponse`] - Unbuffered NDJSON and JSON array responses (requires `stream` feature)
+//! - `LIMIT`/`OFFSET` clauses and count queries for `sqlx` (requires `sqlx` feature)
+//! - [`PageLinks`] - RFC 8288 `Link` headers for paginated responses
+//! - [`PaginationStyle`] - Enveloped or header-based paginated responses
+//! - [`ResponseFormat`] - JSON or CSV list responses (requires `csv` feature)
+//! - [`EnvelopeFields`] - Custom field names of the paginated response envelope
+//! - [`ItemRange`] - `Range: items=...` extractor and `206 Partial Content` responses
+//! - [`PaginationError`] - Errors produced when converting or validating pagination parameters
+
+#[cfg(feature = "cursor-codec")]
+mod codec;
+#[cfg(feature = "csv")]
+mod csv_response;
+mod cursor;
+mod error;
+mod extract;
+mod fields;
+mod keyset;
+mod links;
+mod offset;
+mod page;
+#[cfg(feature = "stream")]
+mod paginator;
+mod range;
+mod sort;
+#[cfg(feature = "sqlx")]
+mod sql;
+#[cfg(feature = "stream")]
+mod streaming;
+mod style;
+#[cfg(feature = "stream")]
+mod walker;
+
+#[cfg(feature = "cursor-codec")]
+pub use codec::*;
+#[cfg(feature = "csv")]
+pub use csv_response::*;
+pub use cursor::*;
+pub use error::*;
+pub use extract::*;
+pub use fields::*;
+pub use keyset::*;
+pub use links::*;
+pub use offset::*;
+pub use page::*;
+#[cfg(feature = "stream")]
+pub use paginator::*;
+pub use range::*;
+pub use sort::*;
+#[cfg(feature = "stream")]
+pub use streaming::*;
+pub use style::*;
+#[cfg(feature = "stream")]
+pub use walker::*;
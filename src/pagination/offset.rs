@@ -0,0 +1,404 @@
+// Copyright (C) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Offset/limit-based pagination.
+
+use serde::{Deserialize, Serialize};
+
+use super::PaginationError;
+use crate::ApiError;
+
+/// A paginated response wrapper.
+///
+/// Provides consistent pagination metadata for list endpoints.
+///
+/// # Example
+///
+/// ```
+/// use bel7_axum::PaginatedResponse;
+///
+/// let items = vec!["a", "b", "c"];
+/// let response = PaginatedResponse::new(items, 100, Some(10), 0);
+///
+/// assert_eq!(response.data.len(), 3);
+/// assert_eq!(response.total, 100);
+/// assert!(response.has_more);
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct PaginatedResponse<T> {
+    /// This page's items.
+    pub data: Vec<T>,
+
+    /// Total number of items across all pages.
+    ///
+    /// See `total_kind` for whether this value is exact.
+    pub total: u64,
+
+    /// Whether `total` is exact, an estimate or unknown.
+    /// Omitted from JSON when exact, so exact pages keep the original shape.
+    #[serde(default, skip_serializing_if = "TotalKind::is_exact")]
+    pub total_kind: TotalKind,
+
+    /// Limit per page (optional).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+
+    /// Number of items skipped.
+    pub offset: u64,
+
+    /// Whether there are more items after this page.
+    pub has_more: bool,
+
+    /// URL of the next page, if any.
+    ///
+    /// See [`PaginatedResponse::with_page_urls`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub next: Option<String>,
+
+    /// URL of the previous page, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub previous: Option<String>,
+}
+
+impl<T> PaginatedResponse<T> {
+    /// Creates a new paginated response.
+    ///
+    /// Automatically calculates the `has_more` value based on offset, data length, and total.
+    /// Inputs are not validated, see [`PaginatedResponse::try_new`].
+    pub fn new(data: Vec<T>, total: u64, limit: Option<u64>, offset: u64) -> Self {
+        let returned = data.len() as u64;
+        let has_more = offset.saturating_add(returned) < total;
+
+        Self {
+            data,
+            total,
+            total_kind: TotalKind::Exact,
+            limit,
+            offset,
+            has_more,
+            next: None,
+            previous: None,
+        }
+    }
+
+    /// Creates a paginated response, validating that the inputs are consistent.
+    ///
+    /// Fails if `offset` plus the number of items overflows, if there are more
+    /// items than `limit`, or if the items extend past `total`. An empty page
+    /// past the end is accepted, see [`PaginatedResponse::check_offset`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bel7_axum::{PaginatedResponse, PaginationError};
+    ///
+    /// assert!(PaginatedResponse::try_new(vec![1, 2], 10, Some(2), 8).is_ok());
+    /// assert_eq!(
+    ///     PaginatedResponse::try_new(vec![1, 2, 3], 10, Some(2), 0).unwrap_err(),
+    ///     PaginationError::PageTooLarge { returned: 3, limit: 2 }
+    /// );
+    /// ```
+    pub fn try_new(
+        data: Vec<T>,
+        total: u64,
+        limit: Option<u64>,
+        offset: u64,
+    ) -> Result<Self, PaginationError> {
+        let returned = data.len() as u64;
+        let end = offset
+            .checked_add(returned)
+            .ok_or(PaginationError::Overflow { offset, returned })?;
+        if let Some(limit) = limit.filter(|&limit| returned > limit) {
+            return Err(PaginationError::PageTooLarge { returned, limit });
+        }
+        if returned > 0 && end > total {
+            return Err(PaginationError::ExceedsTotal {
+                offset,
+                returned,
+                total,
+            });
+        }
+
+        Ok(Self::new(data, total, limit, offset))
+    }
+
+    /// Applies a [`PastEndPolicy`] to this page.
+    ///
+    /// A page is past the end of the collection if its non-zero offset is at or
+    /// beyond the exact total. When the total is estimated or unknown, it is
+    /// not relied upon: an empty page with a non-zero offset is past the end.
+    /// Such a page is returned as is, or rejected with [`ApiError::NotFound`]
+    /// or [`ApiError::BadRequest`], depending on the policy.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bel7_axum::{ApiError, PaginatedResponse, PastEndPolicy};
+    ///
+    /// let page = PaginatedResponse::<u32>::new(vec![], 10, Some(5), 20);
+    /// assert!(matches!(
+    ///     page.check_offset(PastEndPolicy::NotFound),
+    ///     Err(ApiError::NotFound(_))
+    /// ));
+    /// ```
+    pub fn check_offset(self, policy: PastEndPolicy) -> Result<Self, ApiError> {
+        let past_end = if self.is_total_exact() {
+            self.offset >= self.total
+        } else {
+            self.data.is_empty()
+        };
+        if !past_end || self.offset == 0 {
+            return Ok(self);
+        }
+
+        let err = PaginationError::OffsetPastEnd {
+            offset: self.offset,
+            total: self.total,
+        };
+        match policy {
+            PastEndPolicy::EmptyPage => Ok(self),
+            PastEndPolicy::NotFound => Err(ApiError::NotFound(err.to_string())),
+            PastEndPolicy::BadRequest => Err(err.into()),
+        }
+    }
+
+    /// Creates a paginated response without counting all items.
+    ///
+    /// `rows` are expected to be fetched with `LIMIT limit + 1`: the extra row,
+    /// if present, only signals that there are more items and is dropped.
+    /// The total is marked as [`TotalKind::Unknown`] and set to the number
+    /// of items seen so far (`offset` plus the returned items), a lower bound.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bel7_axum::{PaginatedResponse, TotalKind};
+    ///
+    /// // SELECT ... LIMIT 3 OFFSET 20 for a page size of 2
+    /// let rows = vec!["u", "v", "w"];
+    /// let page = PaginatedResponse::from_probe(rows, 2, 20);
+    ///
+    /// assert_eq!(page.data, vec!["u", "v"]);
+    /// assert!(page.has_more);
+    /// assert_eq!(page.total_kind, TotalKind::Unknown);
+    /// ```
+    pub fn from_probe(mut rows: Vec<T>, limit: u64, offset: u64) -> Self {
+        let has_more = rows.len() as u64 > limit;
+        rows.truncate(to_usize(limit));
+        let total = offset.saturating_add(rows.len() as u64);
+
+        Self {
+            data: rows,
+            total,
+            total_kind: TotalKind::Unknown,
+            limit: Some(limit),
+            offset,
+            has_more,
+            next: None,
+            previous: None,
+        }
+    }
+
+    /// Replaces the total with an estimate, e.g. from table statistics.
+    ///
+    /// `has_more` is left as is. The estimate is raised to at least
+    /// the number of items seen so far.
+    pub fn with_estimated_total(mut self, estimate: u64) -> Self {
+        let seen = self.offset.saturating_add(self.data.len() as u64);
+        self.total = estimate.max(seen);
+        self.total_kind = TotalKind::Estimated;
+        self
+    }
+
+    /// Returns `true` if `total` is an exact count.
+    pub fn is_total_exact(&self) -> bool {
+        self.total_kind.is_exact()
+    }
+
+    /// Creates a paginated response for a single page with all items.
+    pub fn single_page(data: Vec<T>) -> Self {
+        let total = data.len() as u64;
+        Self {
+            data,
+            total,
+            total_kind: TotalKind::Exact,
+            limit: None,
+            offset: 0,
+            has_more: false,
+            next: None,
+            previous: None,
+        }
+    }
+
+    /// Paginates an in-memory slice.
+    ///
+    /// The limit is taken from the query and clamped to `max`.
+    /// Offsets past the end produce an empty page.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bel7_axum::{PaginatedResponse, PaginationQuery};
+    ///
+    /// let items = ["a", "b", "c", "d", "e"];
+    /// let query = PaginationQuery { limit: Some(2), offset: Some(2) };
+    /// let page = PaginatedResponse::from_slice(&items, &query, 100);
+    ///
+    /// assert_eq!(page.data, vec!["c", "d"]);
+    /// assert_eq!(page.total, 5);
+    /// assert!(page.has_more);
+    /// ```
+    pub fn from_slice(items: &[T], query: &PaginationQuery, max: u64) -> Self
+    where
+        T: Clone,
+    {
+        let limit = query.effective_limit(max);
+        let offset = query.effective_offset();
+        let start = to_usize(offset).min(items.len());
+        let end = start.saturating_add(to_usize(limit)).min(items.len());
+
+        Self::new(
+            items[start..end].to_vec(),
+            items.len() as u64,
+            Some(limit),
+            offset,
+        )
+    }
+
+    /// Paginates the items of an iterator.
+    ///
+    /// The iterator is consumed entirely to compute `total`, but only
+    /// the items of the requested page are kept.
+    pub fn from_iter<I>(items: I, query: &PaginationQuery, max: u64) -> Self
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let limit = query.effective_limit(max);
+        let offset = query.effective_offset();
+        let start = to_usize(offset);
+        let end = start.saturating_add(to_usize(limit));
+
+        let mut total: u64 = 0;
+        let mut data = Vec::new();
+        for (i, item) in items.into_iter().enumerate() {
+            if i >= start && i < end {
+                data.push(item);
+            }
+            total += 1;
+        }
+
+        Self::new(data, total, Some(limit), offset)
+    }
+
+    /// Maps the data items using the provided function.
+    pub fn map<U, F>(self, f: F) -> PaginatedResponse<U>
+    where
+        F: FnMut(T) -> U,
+    {
+        PaginatedResponse {
+            data: self.data.into_iter().map(f).collect(),
+            total: self.total,
+            total_kind: self.total_kind,
+            limit: self.limit,
+            offset: self.offset,
+            has_more: self.has_more,
+            next: self.next,
+            previous: self.previous,
+        }
+    }
+}
+
+/// Whether the `total` of a [`PaginatedResponse`] is exact.
+///
+/// Serialized as `"exact"`, `"estimated"` or `"unknown"`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+#[serde(rename_all = "lowercase")]
+pub enum TotalKind {
+    /// `total` is an exact count.
+    #[default]
+    Exact,
+
+    /// `total` is an estimate.
+    Estimated,
+
+    /// `total` is unknown and holds the number of items seen so far.
+    Unknown,
+}
+
+impl TotalKind {
+    /// Returns `true` for [`TotalKind::Exact`].
+    pub fn is_exact(&self) -> bool {
+        *self == TotalKind::Exact
+    }
+}
+
+/// What to do with a page whose offset is past the end of the collection.
+///
+/// See [`PaginatedResponse::check_offset`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum PastEndPolicy {
+    /// Respond with an empty page.
+    #[default]
+    EmptyPage,
+
+    /// Reject with [`ApiError::NotFound`].
+    NotFound,
+
+    /// Reject with [`ApiError::BadRequest`].
+    BadRequest,
+}
+
+/// Query parameters for pagination.
+/// Meant to be used with [`axum::extract::Query`].
+///
+/// # Example
+///
+/// ```ignore
+/// use axum::extract::Query;
+/// use bel7_axum::PaginationQuery;
+///
+/// async fn list_items(Query(pagination): Query<PaginationQuery>) -> impl IntoResponse {
+///     let limit = pagination.effective_limit(100);
+///     let offset = pagination.offset.unwrap_or(0);
+///     // ...
+/// }
+/// ```
+#[derive(Debug, Clone, Default, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::IntoParams))]
+#[cfg_attr(feature = "openapi", into_params(parameter_in = Query))]
+pub struct PaginationQuery {
+    /// Maximum number of items to return.
+    pub limit: Option<u64>,
+
+    /// Number of items to skip.
+    pub offset: Option<u64>,
+}
+
+impl PaginationQuery {
+    /// Returns the effective limit, clamped to a maximum value if needed.
+    pub fn effective_limit(&self, max: u64) -> u64 {
+        self.limit.unwrap_or(max).min(max)
+    }
+
+    /// Returns the offset, defaulting to 0.
+    pub fn effective_offset(&self) -> u64 {
+        self.offset.unwrap_or(0)
+    }
+}
+
+fn to_usize(value: u64) -> usize {
+    usize::try_from(value).unwrap_or(usize::MAX)
+}
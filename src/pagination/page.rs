@@ -0,0 +1,200 @@
+// Copyright (C) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Page number-based pagination.
+//!
+//! Pages are numbered starting from 1.
+
+use serde::{Deserialize, Serialize};
+
+use super::{PaginatedResponse, PaginationError, PaginationQuery};
+
+/// Query parameters for page number-based pagination.
+/// Meant to be used with [`axum::extract::Query`].
+///
+/// # Example
+///
+/// ```
+/// use bel7_axum::PageQuery;
+///
+/// let q = PageQuery { page: Some(3), per_page: Some(25) };
+/// let pagination = q.to_pagination_query(100);
+///
+/// assert_eq!(pagination.limit, Some(25));
+/// assert_eq!(pagination.offset, Some(50));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::IntoParams))]
+#[cfg_attr(feature = "openapi", into_params(parameter_in = Query))]
+pub struct PageQuery {
+    /// Page number, starting from 1.
+    pub page: Option<u64>,
+
+    /// Number of items per page.
+    pub per_page: Option<u64>,
+}
+
+impl PageQuery {
+    /// Returns the page number, defaulting to (and at least) 1.
+    pub fn effective_page(&self) -> u64 {
+        self.page.unwrap_or(1).max(1)
+    }
+
+    /// Returns the page size, clamped to `1..=max`.
+    pub fn effective_per_page(&self, max: u64) -> u64 {
+        self.per_page.unwrap_or(max).min(max).max(1)
+    }
+
+    /// Converts this query to the equivalent limit/offset query.
+    pub fn to_pagination_query(&self, max: u64) -> PaginationQuery {
+        let per_page = self.effective_per_page(max);
+        PaginationQuery {
+            limit: Some(per_page),
+            offset: Some((self.effective_page() - 1).saturating_mul(per_page)),
+        }
+    }
+}
+
+impl TryFrom<&PaginationQuery> for PageQuery {
+    type Error = PaginationError;
+
+    /// Converts a limit/offset query whose offset falls on a page boundary.
+    fn try_from(query: &PaginationQuery) -> Result<Self, Self::Error> {
+        let limit = match query.limit {
+            Some(limit) if limit > 0 => limit,
+            _ => return Err(PaginationError::MissingLimit),
+        };
+        let offset = query.effective_offset();
+        if offset % limit != 0 {
+            return Err(PaginationError::UnalignedOffset { offset, limit });
+        }
+
+        Ok(Self {
+            page: Some(offset / limit + 1),
+            per_page: Some(limit),
+        })
+    }
+}
+
+/// A page number-based paginated response.
+///
+/// # Example
+///
+/// ```
+/// use bel7_axum::PagedResponse;
+///
+/// let response = PagedResponse::new(vec!["a", "b"], 7, 2, 2);
+///
+/// assert_eq!(response.total_pages, 4);
+/// assert_eq!(response.next_page, Some(3));
+/// assert_eq!(response.prev_page, Some(1));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct PagedResponse<T> {
+    /// This page's items.
+    pub data: Vec<T>,
+
+    /// Total number of items across all pages.
+    pub total: u64,
+
+    /// Current page number, starting from 1.
+    pub page: u64,
+
+    /// Number of items per page.
+    pub per_page: u64,
+
+    /// Total number of pages.
+    pub total_pages: u64,
+
+    /// Next page number, if there is one.
+    pub next_page: Option<u64>,
+
+    /// Previous page number, if there is one.
+    pub prev_page: Option<u64>,
+}
+
+impl<T> PagedResponse<T> {
+    /// Creates a new page-based response.
+    ///
+    /// `page` and `per_page` are treated as at least 1.
+    pub fn new(data: Vec<T>, total: u64, page: u64, per_page: u64) -> Self {
+        let page = page.max(1);
+        let per_page = per_page.max(1);
+        let total_pages = total.div_ceil(per_page);
+
+        Self {
+            data,
+            total,
+            page,
+            per_page,
+            total_pages,
+            next_page: (page < total_pages).then(|| page + 1),
+            prev_page: (page > 1).then(|| page - 1),
+        }
+    }
+
+    /// Maps the data items using the provided function.
+    pub fn map<U, F>(self, f: F) -> PagedResponse<U>
+    where
+        F: FnMut(T) -> U,
+    {
+        PagedResponse {
+            data: self.data.into_iter().map(f).collect(),
+            total: self.total,
+            page: self.page,
+            per_page: self.per_page,
+            total_pages: self.total_pages,
+            next_page: self.next_page,
+            prev_page: self.prev_page,
+        }
+    }
+}
+
+/// Page 0 is treated as page 1, like in [`PagedResponse::new`].
+impl<T> From<PagedResponse<T>> for PaginatedResponse<T> {
+    fn from(paged: PagedResponse<T>) -> Self {
+        let offset = paged.page.saturating_sub(1).saturating_mul(paged.per_page);
+        PaginatedResponse::new(paged.data, paged.total, Some(paged.per_page), offset)
+    }
+}
+
+impl<T> TryFrom<PaginatedResponse<T>> for PagedResponse<T> {
+    type Error = PaginationError;
+
+    /// Converts a response with an exact total, a limit set and an offset
+    /// that falls on a page boundary.
+    ///
+    /// Estimated and unknown totals are rejected: page counts derived from
+    /// them would end pagination before `has_more` does.
+    fn try_from(response: PaginatedResponse<T>) -> Result<Self, Self::Error> {
+        if !response.is_total_exact() {
+            return Err(PaginationError::InexactTotal {
+                kind: response.total_kind,
+            });
+        }
+        let query = PaginationQuery {
+            limit: response.limit,
+            offset: Some(response.offset),
+        };
+        let page = PageQuery::try_from(&query)?;
+
+        Ok(Self::new(
+            response.data,
+            response.total,
+            page.effective_page(),
+            page.per_page.unwrap_or(1),
+        ))
+    }
+}
@@ -0,0 +1,191 @@
+// Copyright (C) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Paginated data sources and streams over all of their pages.
+
+use std::future::Future;
+
+use futures_util::stream::{self, Stream, StreamExt};
+
+use super::{CursorPaginatedResponse, CursorQuery, PaginatedResponse, PaginationQuery};
+
+/// A data source that returns offset/limit-paginated results.
+///
+/// The same implementation can back a paginated HTTP endpoint (via
+/// [`Paginator::fetch_page`]) and jobs that need every item (via [`Paginator::items`]).
+///
+/// # Example
+///
+/// ```ignore
+/// use bel7_axum::{PaginatedResponse, PaginationQuery, Paginator};
+///
+/// struct Users {
+///     pool: PgPool,
+/// }
+///
+/// impl Paginator for Users {
+///     type Item = User;
+///     type Error = sqlx::Error;
+///
+///     async fn fetch_page(&self, query: PaginationQuery) -> Result<PaginatedResponse<User>, sqlx::Error> {
+///         // SELECT ... LIMIT $1 OFFSET $2
+///     }
+/// }
+///
+/// // in a background export job
+/// let mut users = std::pin::pin!(Users { pool }.items(500));
+/// while let Some(user) = users.next().await {
+///     export(user?).await;
+/// }
+/// ```
+pub trait Paginator {
+    /// The type of items returned.
+    type Item;
+
+    /// The error type of the data source.
+    type Error;
+
+    /// Fetches a single page.
+    fn fetch_page(
+        &self,
+        query: PaginationQuery,
+    ) -> impl Future<Output = Result<PaginatedResponse<Self::Item>, Self::Error>> + Send;
+
+    /// Returns a stream of all pages, fetched with the given page size.
+    ///
+    /// The stream ends after a page with `has_more == false`, an empty page or an error.
+    fn pages(
+        self,
+        page_size: u64,
+    ) -> impl Stream<Item = Result<PaginatedResponse<Self::Item>, Self::Error>>
+    where
+        Self: Sized,
+    {
+        offset_pages(self, Some(page_size), 0, |paginator, query| async move {
+            let result = paginator.fetch_page(query).await;
+            (paginator, result)
+        })
+    }
+
+    /// Returns a stream of all items across all pages.
+    fn items(self, page_size: u64) -> impl Stream<Item = Result<Self::Item, Self::Error>>
+    where
+        Self: Sized,
+    {
+        self.pages(page_size)
+            .flat_map(|page| stream::iter(flatten_page(page.map(|p| p.data))))
+    }
+}
+
+/// A data source that returns cursor-paginated results.
+pub trait CursorPaginator {
+    /// The type of items returned.
+    type Item;
+
+    /// The error type of the data source.
+    type Error;
+
+    /// Fetches a single page.
+    fn fetch_page(
+        &self,
+        query: CursorQuery,
+    ) -> impl Future<Output = Result<CursorPaginatedResponse<Self::Item>, Self::Error>> + Send;
+
+    /// Returns a stream of all pages, fetched with the given page size.
+    ///
+    /// The stream ends after a page with `has_more == false`, without
+    /// a next cursor, an empty page or an error.
+    fn pages(
+        self,
+        page_size: u64,
+    ) -> impl Stream<Item = Result<CursorPaginatedResponse<Self::Item>, Self::Error>>
+    where
+        Self: Sized,
+    {
+        stream::unfold(Some((self, None)), move |state| async move {
+            let (paginator, cursor) = state?;
+            let query = CursorQuery {
+                cursor,
+                before: None,
+                limit: Some(page_size),
+            };
+            match paginator.fetch_page(query).await {
+                Ok(page) => {
+                    let next = match (&page.next_cursor, page.has_more, page.data.is_empty()) {
+                        (Some(cursor), true, false) => Some((paginator, Some(cursor.clone()))),
+                        _ => None,
+                    };
+                    Some((Ok(page), next))
+                }
+                Err(e) => Some((Err(e), None)),
+            }
+        })
+    }
+
+    /// Returns a stream of all items across all pages.
+    fn items(self, page_size: u64) -> impl Stream<Item = Result<Self::Item, Self::Error>>
+    where
+        Self: Sized,
+    {
+        self.pages(page_size)
+            .flat_map(|page| stream::iter(flatten_page(page.map(|p| p.data))))
+    }
+}
+
+/// Streams offset/limit pages, starting at `offset`, until a page with
+/// `has_more == false`, an empty page or an error.
+///
+/// `fetch` takes the data source by value and hands it back along with the
+/// result, so that sources with `&self` and `&mut self` fetch methods work alike.
+pub(crate) fn offset_pages<S, T, E, F, Fut>(
+    source: S,
+    limit: Option<u64>,
+    offset: u64,
+    fetch: F,
+) -> impl Stream<Item = Result<PaginatedResponse<T>, E>>
+where
+    F: Fn(S, PaginationQuery) -> Fut,
+    Fut: Future<Output = (S, Result<PaginatedResponse<T>, E>)>,
+{
+    stream::unfold(Some((source, offset)), move |state| {
+        let pending = state.map(|(source, offset)| {
+            let query = PaginationQuery {
+                limit,
+                offset: Some(offset),
+            };
+            (fetch(source, query), offset)
+        });
+        async move {
+            let (pending, offset) = pending?;
+            let (source, result) = pending.await;
+            match result {
+                Ok(page) => {
+                    let returned = page.data.len() as u64;
+                    let next = (page.has_more && returned > 0)
+                        .then(|| (source, offset.saturating_add(returned)));
+                    Some((Ok(page), next))
+                }
+                Err(e) => Some((Err(e), None)),
+            }
+        }
+    })
+}
+
+/// Turns a page of items into item results.
+pub(crate) fn flatten_page<T, E>(page: Result<Vec<T>, E>) -> Vec<Result<T, E>> {
+    match page {
+        Ok(data) => data.into_iter().map(Ok).collect(),
+        Err(e) => vec![Err(e)],
+    }
+}
@@ -0,0 +1,229 @@
+// Copyright (C) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! `Range: items=...` requests and `206 Partial Content` responses.
+
+use axum::Json;
+use axum::extract::{FromRequestParts, OptionalFromRequestParts};
+use axum::response::{IntoResponse, Response};
+use http::request::Parts;
+use http::{HeaderValue, StatusCode, header};
+use serde::Serialize;
+
+use super::{PaginatedResponse, PaginationQuery};
+use crate::{ApiError, json_error};
+
+/// The range unit used by [`ItemRange`] and in `Accept-Ranges` headers.
+pub const ITEMS_RANGE_UNIT: &str = "items";
+
+/// An item range requested with a `Range: items=first-last` header.
+///
+/// Both bounds are inclusive and zero-based. An open-ended range
+/// (`items=25-`) has no `last` bound. Only a single range is supported.
+///
+/// Malformed headers are rejected with [`ApiError::BadRequest`]. Use
+/// `Option<ItemRange>` to make the header optional.
+///
+/// # Example
+///
+/// ```ignore
+/// use bel7_axum::ItemRange;
+///
+/// // Range: items=0-24
+/// async fn list_items(range: Option<ItemRange>) -> Response {
+///     let query = range.map(|r| r.to_pagination_query(100)).unwrap_or_default();
+///     let page = fetch_page(&query).await;
+///     page.into_range_response(range)
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ItemRange {
+    /// Index of the first requested item.
+    pub first: u64,
+
+    /// Index of the last requested item, if bounded.
+    pub last: Option<u64>,
+}
+
+impl ItemRange {
+    /// Parses a `Range` header value, e.g. `items=0-24`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bel7_axum::ItemRange;
+    ///
+    /// let range = ItemRange::parse("items=10-19").unwrap();
+    /// assert_eq!(range, ItemRange { first: 10, last: Some(19) });
+    ///
+    /// let query = range.to_pagination_query(100);
+    /// assert_eq!((query.limit, query.offset), (Some(10), Some(10)));
+    /// ```
+    pub fn parse(value: &str) -> Result<Self, ApiError> {
+        let invalid = || ApiError::BadRequest(format!("Invalid item range '{value}'"));
+
+        let (unit, spec) = value.trim().split_once('=').ok_or_else(invalid)?;
+        if !unit.trim().eq_ignore_ascii_case(ITEMS_RANGE_UNIT) {
+            return Err(ApiError::BadRequest(format!(
+                "Unsupported range unit '{}'",
+                unit.trim()
+            )));
+        }
+        if spec.contains(',') {
+            return Err(ApiError::BadRequest(
+                "Multiple item ranges are not supported".into(),
+            ));
+        }
+
+        let (first, last) = spec.trim().split_once('-').ok_or_else(invalid)?;
+        let first: u64 = first.trim().parse().map_err(|_| invalid())?;
+        let last = match last.trim() {
+            "" => None,
+            last => Some(last.parse::<u64>().map_err(|_| invalid())?),
+        };
+        if last.is_some_and(|last| last < first) {
+            return Err(invalid());
+        }
+
+        Ok(Self { first, last })
+    }
+
+    /// Returns the number of requested items, if bounded.
+    pub fn count(&self) -> Option<u64> {
+        self.last.map(|last| (last - self.first).saturating_add(1))
+    }
+
+    /// Returns the equivalent [`PaginationQuery`], with the limit clamped to `max`.
+    pub fn to_pagination_query(&self, max: u64) -> PaginationQuery {
+        PaginationQuery {
+            limit: Some(self.count().unwrap_or(max).min(max)),
+            offset: Some(self.first),
+        }
+    }
+}
+
+impl From<ItemRange> for PaginationQuery {
+    fn from(range: ItemRange) -> Self {
+        PaginationQuery {
+            limit: range.count(),
+            offset: Some(range.first),
+        }
+    }
+}
+
+fn range_header(parts: &Parts) -> Result<Option<ItemRange>, ApiError> {
+    match parts.headers.get(header::RANGE) {
+        None => Ok(None),
+        Some(value) => {
+            let value = value
+                .to_str()
+                .map_err(|_| ApiError::BadRequest("Invalid Range header".into()))?;
+            ItemRange::parse(value).map(Some)
+        }
+    }
+}
+
+impl<S> FromRequestParts<S> for ItemRange
+where
+    S: Send + Sync,
+{
+    type Rejection = ApiError;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        range_header(parts)?.ok_or_else(|| ApiError::BadRequest("Missing Range header".into()))
+    }
+}
+
+impl<S> OptionalFromRequestParts<S> for ItemRange
+where
+    S: Send + Sync,
+{
+    type Rejection = ApiError;
+
+    async fn from_request_parts(
+        parts: &mut Parts,
+        _state: &S,
+    ) -> Result<Option<Self>, Self::Rejection> {
+        range_header(parts)
+    }
+}
+
+impl<T> PaginatedResponse<T> {
+    /// Returns the `Content-Range` header value for this page, e.g. `items 0-24/319`.
+    ///
+    /// The total is `*` when it is not exact. Returns `items */total`
+    /// for an empty page.
+    pub fn content_range(&self) -> HeaderValue {
+        let total = if self.is_total_exact() {
+            self.total.to_string()
+        } else {
+            "*".to_string()
+        };
+        let value = match self.data.len() as u64 {
+            0 => format!("{ITEMS_RANGE_UNIT} */{total}"),
+            len => format!(
+                "{ITEMS_RANGE_UNIT} {}-{}/{total}",
+                self.offset,
+                self.offset.saturating_add(len - 1)
+            ),
+        };
+        HeaderValue::from_str(&value).expect("content range is a valid header value")
+    }
+
+    /// Converts this page into a response to a request with an optional `Range` header.
+    ///
+    /// With a range, a non-empty page produces `206 Partial Content` with a
+    /// `Content-Range` header and a bare JSON array of items, and an empty page
+    /// past the start of the collection produces `416 Range Not Satisfiable`
+    /// with `Content-Range: items */total`. Without a range, or for an empty
+    /// collection requested from offset zero, the response is `200 OK` with
+    /// the bare JSON array and no `Content-Range`. All responses carry
+    /// `Accept-Ranges: items`.
+    pub fn into_range_response(self, range: Option<ItemRange>) -> Response
+    where
+        T: Serialize,
+    {
+        let accept_ranges = HeaderValue::from_static(ITEMS_RANGE_UNIT);
+        let content_range = range.map(|_| self.content_range());
+
+        let mut response = match content_range {
+            Some(content_range) if !self.data.is_empty() => (
+                StatusCode::PARTIAL_CONTENT,
+                [(header::CONTENT_RANGE, content_range)],
+                Json(self.data),
+            )
+                .into_response(),
+            Some(content_range) if self.offset > 0 => {
+                let mut response = json_error(
+                    StatusCode::RANGE_NOT_SATISFIABLE,
+                    "Range Not Satisfiable",
+                    Some(format!(
+                        "Offset {} is past the end of the collection",
+                        self.offset
+                    )),
+                );
+                response
+                    .headers_mut()
+                    .insert(header::CONTENT_RANGE, content_range);
+                response
+            }
+            _ => Json(self.data).into_response(),
+        };
+
+        response
+            .headers_mut()
+            .insert(header::ACCEPT_RANGES, accept_ranges);
+        response
+    }
+}
@@ -0,0 +1,252 @@
+// Copyright (C) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Sort specification and list query parsing.
+
+use std::collections::BTreeMap;
+use std::fmt;
+
+use axum::extract::FromRequestParts;
+use http::request::Parts;
+
+use super::PaginationQuery;
+use super::extract::parse_u64_param;
+use crate::ApiError;
+
+/// Sort direction of a single field.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum SortDirection {
+    /// Ascending order.
+    #[default]
+    Asc,
+    /// Descending order.
+    Desc,
+}
+
+impl SortDirection {
+    /// Returns the opposite direction.
+    pub fn reversed(self) -> Self {
+        match self {
+            SortDirection::Asc => SortDirection::Desc,
+            SortDirection::Desc => SortDirection::Asc,
+        }
+    }
+
+    /// Returns the SQL keyword for this direction.
+    pub fn as_sql(self) -> &'static str {
+        match self {
+            SortDirection::Asc => "ASC",
+            SortDirection::Desc => "DESC",
+        }
+    }
+}
+
+/// A field to sort by, with its direction.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SortField {
+    /// Field name, one of the allowed sortable fields.
+    pub name: String,
+    /// Sort direction.
+    pub direction: SortDirection,
+}
+
+impl fmt::Display for SortField {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.direction {
+            SortDirection::Asc => write!(f, "{}", self.name),
+            SortDirection::Desc => write!(f, "-{}", self.name),
+        }
+    }
+}
+
+/// A parsed sort specification such as `-created_at,name`.
+///
+/// A `-` prefix means descending order, a `+` prefix or no prefix means ascending.
+///
+/// # Example
+///
+/// ```
+/// use bel7_axum::{SortDirection, SortSpec};
+///
+/// let spec = SortSpec::parse("-created_at,name", &["created_at", "name"]).unwrap();
+///
+/// assert_eq!(spec.fields[0].name, "created_at");
+/// assert_eq!(spec.fields[0].direction, SortDirection::Desc);
+/// assert_eq!(spec.order_by().as_deref(), Some("created_at DESC, name ASC"));
+///
+/// assert!(SortSpec::parse("password", &["created_at", "name"]).is_err());
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct SortSpec {
+    /// Fields to sort by, in order of precedence.
+    pub fields: Vec<SortField>,
+}
+
+impl SortSpec {
+    /// Parses a comma-separated sort specification.
+    ///
+    /// Returns [`ApiError::BadRequest`] for fields that are not in `allowed`
+    /// and for fields that are listed more than once.
+    pub fn parse(input: &str, allowed: &[&str]) -> Result<Self, ApiError> {
+        let mut fields: Vec<SortField> = Vec::new();
+        for part in input.split(',').map(str::trim).filter(|p| !p.is_empty()) {
+            let (name, direction) = match part.strip_prefix('-') {
+                Some(name) => (name, SortDirection::Desc),
+                None => (part.strip_prefix('+').unwrap_or(part), SortDirection::Asc),
+            };
+
+            if !allowed.contains(&name) {
+                return Err(ApiError::BadRequest(format!(
+                    "cannot sort by '{name}', sortable fields: {}",
+                    allowed.join(", ")
+                )));
+            }
+            if fields.iter().any(|f| f.name == name) {
+                return Err(ApiError::BadRequest(format!(
+                    "sort field '{name}' is listed more than once"
+                )));
+            }
+            fields.push(SortField {
+                name: name.to_string(),
+                direction,
+            });
+        }
+        Ok(Self { fields })
+    }
+
+    /// Returns `true` if no fields are specified.
+    pub fn is_empty(&self) -> bool {
+        self.fields.is_empty()
+    }
+
+    /// Returns a specification with every direction reversed.
+    pub fn reversed(&self) -> Self {
+        Self {
+            fields: self
+                .fields
+                .iter()
+                .map(|f| SortField {
+                    name: f.name.clone(),
+                    direction: f.direction.reversed(),
+                })
+                .collect(),
+        }
+    }
+
+    /// Renders the fields as the body of an SQL `ORDER BY` clause.
+    ///
+    /// Field names are used verbatim, which is safe as long as the
+    /// allowed fields passed to [`SortSpec::parse`] are trusted column names.
+    pub fn order_by(&self) -> Option<String> {
+        if self.is_empty() {
+            return None;
+        }
+        let clauses: Vec<String> = self
+            .fields
+            .iter()
+            .map(|f| format!("{} {}", f.name, f.direction.as_sql()))
+            .collect();
+        Some(clauses.join(", "))
+    }
+}
+
+impl fmt::Display for SortSpec {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, field) in self.fields.iter().enumerate() {
+            if i > 0 {
+                f.write_str(",")?;
+            }
+            write!(f, "{field}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Extracts pagination, sort and filter query parameters of a list endpoint.
+///
+/// `limit` and `offset` go into [`ListQuery::pagination`], `sort` is kept
+/// as-is until validated with [`ListQuery::sort_spec`], and all other
+/// parameters are collected as filters. Malformed `limit` or `offset`
+/// values are rejected with [`ApiError::BadRequest`].
+///
+/// # Example
+///
+/// ```ignore
+/// use bel7_axum::ListQuery;
+///
+/// // GET /users?sort=-created_at,name&status=active&limit=20
+/// async fn list_users(query: ListQuery) -> Result<PaginatedResponse<User>, ApiError> {
+///     let sort = query.sort_spec(&["created_at", "name", "email"])?;
+///     let status = query.filter("status");
+///     let limit = query.pagination.effective_limit(100);
+///     // ...
+/// }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ListQuery {
+    /// The `limit` and `offset` parameters.
+    pub pagination: PaginationQuery,
+
+    /// The raw `sort` parameter.
+    pub sort: Option<String>,
+
+    /// All other query parameters. For repeated parameters, the last value wins.
+    pub filters: BTreeMap<String, String>,
+}
+
+impl ListQuery {
+    /// Parses a query string, e.g. `sort=-name&status=active&limit=10`.
+    pub fn from_query(query: &str) -> Result<Self, ApiError> {
+        let mut list_query = Self::default();
+        for (key, value) in form_urlencoded::parse(query.as_bytes()) {
+            match key.as_ref() {
+                "limit" => list_query.pagination.limit = Some(parse_u64_param("limit", &value)?),
+                "offset" => list_query.pagination.offset = Some(parse_u64_param("offset", &value)?),
+                "sort" => list_query.sort = Some(value.into_owned()),
+                _ => {
+                    list_query
+                        .filters
+                        .insert(key.into_owned(), value.into_owned());
+                }
+            }
+        }
+        Ok(list_query)
+    }
+
+    /// Parses and validates the `sort` parameter against the allowed fields.
+    ///
+    /// Returns an empty specification when `sort` is absent.
+    pub fn sort_spec(&self, allowed: &[&str]) -> Result<SortSpec, ApiError> {
+        match &self.sort {
+            Some(sort) => SortSpec::parse(sort, allowed),
+            None => Ok(SortSpec::default()),
+        }
+    }
+
+    /// Returns the value of a filter parameter.
+    pub fn filter(&self, name: &str) -> Option<&str> {
+        self.filters.get(name).map(String::as_str)
+    }
+}
+
+impl<S> FromRequestParts<S> for ListQuery
+where
+    S: Send + Sync,
+{
+    type Rejection = ApiError;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        Self::from_query(parts.uri.query().unwrap_or(""))
+    }
+}
@@ -0,0 +1,108 @@
+// Copyright (C) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! `LIMIT`/`OFFSET` clause generation for `sqlx` query builders.
+
+use sqlx::{Acquire, Database, Encode, Executor, FromRow, IntoArguments, QueryBuilder, Type};
+
+use super::{PaginatedResponse, PaginationQuery};
+use crate::ApiError;
+
+impl PaginationQuery {
+    /// Appends ` LIMIT ? OFFSET ?` to the query, binding the effective limit and offset.
+    ///
+    /// The limit is clamped to `max`. Values are bound as `i64` and
+    /// saturate at `i64::MAX`.
+    pub fn push_limit_offset<'args, DB>(&self, builder: &mut QueryBuilder<'args, DB>, max: u64)
+    where
+        DB: Database,
+        i64: Encode<'args, DB> + Type<DB> + 'args,
+    {
+        builder.push(" LIMIT ");
+        builder.push_bind(to_i64(self.effective_limit(max)));
+        builder.push(" OFFSET ");
+        builder.push_bind(to_i64(self.effective_offset()));
+    }
+
+    /// Runs a paginated query and its companion count query.
+    ///
+    /// `query` must not have a `LIMIT` or `OFFSET` clause, it is appended here.
+    /// `count` must return a single integer, e.g. `SELECT COUNT(*) FROM ...`
+    /// with the same filters as `query`. Both queries run on the same connection,
+    /// which can be a pool, a connection or a transaction.
+    ///
+    /// Database errors and negative counts are reported as [`ApiError::Internal`].
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// use sqlx::QueryBuilder;
+    /// use bel7_axum::{ApiError, PaginatedResponse, PaginationQuery};
+    ///
+    /// async fn list_users(pool: &PgPool, q: &PaginationQuery) -> Result<PaginatedResponse<User>, ApiError> {
+    ///     let mut query = QueryBuilder::new("SELECT id, name FROM users ORDER BY id");
+    ///     let mut count = QueryBuilder::new("SELECT COUNT(*) FROM users");
+    ///     q.fetch_paginated(pool, &mut query, &mut count, 100).await
+    /// }
+    /// ```
+    pub async fn fetch_paginated<'q, 'c, DB, A, T>(
+        &self,
+        connection: A,
+        query: &'q mut QueryBuilder<'q, DB>,
+        count: &'q mut QueryBuilder<'q, DB>,
+        max: u64,
+    ) -> Result<PaginatedResponse<T>, ApiError>
+    where
+        DB: Database,
+        A: Acquire<'c, Database = DB>,
+        for<'e> &'e mut DB::Connection: Executor<'e, Database = DB>,
+        T: for<'r> FromRow<'r, DB::Row> + Send + Unpin,
+        (i64,): for<'r> FromRow<'r, DB::Row>,
+        i64: Encode<'q, DB> + Type<DB> + 'q,
+        DB::Arguments<'q>: IntoArguments<'q, DB>,
+    {
+        let mut conn = connection.acquire().await.map_err(fetch_error)?;
+
+        let total: i64 = count
+            .build_query_scalar()
+            .fetch_one(&mut *conn)
+            .await
+            .map_err(fetch_error)?;
+        let total = u64::try_from(total).map_err(|_| {
+            ApiError::Internal(format!("count query returned a negative value: {total}"))
+        })?;
+
+        self.push_limit_offset(query, max);
+        let data: Vec<T> = query
+            .build_query_as()
+            .fetch_all(&mut *conn)
+            .await
+            .map_err(fetch_error)?;
+
+        Ok(PaginatedResponse::new(
+            data,
+            total,
+            Some(self.effective_limit(max)),
+            self.effective_offset(),
+        ))
+    }
+}
+
+fn fetch_error(e: sqlx::Error) -> ApiError {
+    ApiError::Internal(format!("failed to fetch page: {e}"))
+}
+
+fn to_i64(value: u64) -> i64 {
+    i64::try_from(value).unwrap_or(i64::MAX)
+}
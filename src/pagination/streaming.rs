@@ -0,0 +1,253 @@
+// Copyright (C) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Streaming NDJSON and JSON array responses.
+
+use std::convert::Infallible;
+use std::pin::Pin;
+
+use axum::body::{Body, Bytes};
+use axum::response::{IntoResponse, Response};
+use futures_util::stream::{self, Stream, StreamExt};
+use http::{HeaderValue, header};
+use serde::{Deserialize, Serialize};
+
+use crate::ApiError;
+
+/// `Content-Type` of newline-delimited JSON responses.
+pub const NDJSON_CONTENT_TYPE: &str = "application/x-ndjson";
+
+/// The wire format of a [`StreamingResponse`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum StreamFormat {
+    /// One JSON document per line (`application/x-ndjson`).
+    #[default]
+    NdJson,
+
+    /// A single JSON array (`application/json`).
+    JsonArray,
+}
+
+/// Metadata emitted after the last item of a [`StreamingResponse`].
+///
+/// Serialized as `{"meta": {"count": ..., "offset": ..., "total": ...}}`,
+/// the last line of an NDJSON stream or the last element of a JSON array.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StreamMetadata {
+    /// Number of items emitted.
+    pub count: u64,
+
+    /// Number of items skipped before the first emitted item.
+    pub offset: u64,
+
+    /// Total number of items, if known.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total: Option<u64>,
+}
+
+#[derive(Serialize)]
+struct MetadataRecord<'a> {
+    meta: &'a StreamMetadata,
+}
+
+/// A response that serializes the items of a stream as they are produced,
+/// without buffering them.
+///
+/// Items are emitted as NDJSON or as a JSON array, followed by a
+/// [`StreamMetadata`] record. If the stream yields an error, an
+/// [`ErrorResponse`](crate::ErrorResponse) record is emitted instead of the metadata and the
+/// response ends. Since the status code has already been sent by then,
+/// clients must check the last record to tell a complete response from
+/// a failed one.
+///
+/// # Example
+///
+/// ```ignore
+/// use bel7_axum::{ApiError, StreamingResponse};
+///
+/// async fn export_users(State(db): State<PgPool>) -> StreamingResponse<impl Stream<...>> {
+///     let users = sqlx::query_as::<_, User>("SELECT * FROM users")
+///         .fetch(&db)
+///         .map(|row| row.map_err(|e| ApiError::Internal(e.to_string())));
+///     StreamingResponse::ndjson(users)
+/// }
+/// ```
+#[derive(Debug)]
+pub struct StreamingResponse<S> {
+    stream: S,
+    format: StreamFormat,
+    offset: u64,
+    total: Option<u64>,
+    metadata: bool,
+}
+
+impl<S> StreamingResponse<S> {
+    /// Creates a streaming response in the given format.
+    pub fn new(stream: S, format: StreamFormat) -> Self {
+        Self {
+            stream,
+            format,
+            offset: 0,
+            total: None,
+            metadata: true,
+        }
+    }
+
+    /// Creates a newline-delimited JSON response.
+    pub fn ndjson(stream: S) -> Self {
+        Self::new(stream, StreamFormat::NdJson)
+    }
+
+    /// Creates a JSON array response.
+    pub fn json_array(stream: S) -> Self {
+        Self::new(stream, StreamFormat::JsonArray)
+    }
+
+    /// Sets the offset reported in the metadata record.
+    pub fn with_offset(mut self, offset: u64) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    /// Sets the total reported in the metadata record.
+    pub fn with_total(mut self, total: u64) -> Self {
+        self.total = Some(total);
+        self
+    }
+
+    /// Omits the metadata record. Error records are still emitted.
+    pub fn without_metadata(mut self) -> Self {
+        self.metadata = false;
+        self
+    }
+}
+
+struct State<S> {
+    items: Pin<Box<S>>,
+    format: StreamFormat,
+    metadata: Option<StreamMetadata>,
+    count: u64,
+    done: bool,
+}
+
+impl<S> State<S> {
+    fn item_chunk(&self, json: Vec<u8>) -> Vec<u8> {
+        match self.format {
+            StreamFormat::NdJson => [json, b"\n".to_vec()].concat(),
+            StreamFormat::JsonArray => {
+                let separator: &[u8] = if self.count == 0 { b"[" } else { b"," };
+                [separator, &json].concat()
+            }
+        }
+    }
+
+    /// The final chunk: an optional trailing record and, for arrays, the closing bracket.
+    fn last_chunk(&self, record: Option<Vec<u8>>) -> Vec<u8> {
+        match (self.format, record) {
+            (StreamFormat::NdJson, Some(record)) => [record, b"\n".to_vec()].concat(),
+            (StreamFormat::NdJson, None) => Vec::new(),
+            (StreamFormat::JsonArray, record) => {
+                let mut chunk = if self.count == 0 {
+                    b"[".to_vec()
+                } else {
+                    Vec::new()
+                };
+                if let Some(record) = record {
+                    if self.count > 0 {
+                        chunk.push(b',');
+                    }
+                    chunk.extend(record);
+                }
+                chunk.push(b']');
+                chunk
+            }
+        }
+    }
+
+    fn error_chunk(&self, err: ApiError) -> Vec<u8> {
+        let record = serde_json::to_vec(&err.to_error_response())
+            .unwrap_or_else(|_| br#"{"error":"Internal Server Error"}"#.to_vec());
+        self.last_chunk(Some(record))
+    }
+
+    fn metadata_chunk(&self) -> Vec<u8> {
+        let record = self.metadata.map(|mut meta| {
+            meta.count = self.count;
+            serde_json::to_vec(&MetadataRecord { meta: &meta })
+                .expect("stream metadata serializes to JSON")
+        });
+        self.last_chunk(record)
+    }
+}
+
+impl<S, T, E> IntoResponse for StreamingResponse<S>
+where
+    S: Stream<Item = Result<T, E>> + Send + 'static,
+    T: Serialize + Send + 'static,
+    E: Into<ApiError> + Send + 'static,
+{
+    fn into_response(self) -> Response {
+        let content_type = match self.format {
+            StreamFormat::NdJson => NDJSON_CONTENT_TYPE,
+            StreamFormat::JsonArray => "application/json",
+        };
+        let state = State {
+            items: Box::pin(self.stream),
+            format: self.format,
+            metadata: self.metadata.then_some(StreamMetadata {
+                count: 0,
+                offset: self.offset,
+                total: self.total,
+            }),
+            count: 0,
+            done: false,
+        };
+
+        let chunks = stream::unfold(state, |mut state| async move {
+            if state.done {
+                return None;
+            }
+            let chunk = match state.items.next().await {
+                Some(Ok(item)) => match serde_json::to_vec(&item) {
+                    Ok(json) => {
+                        let chunk = state.item_chunk(json);
+                        state.count += 1;
+                        chunk
+                    }
+                    Err(e) => {
+                        state.done = true;
+                        state.error_chunk(ApiError::Internal(format!(
+                            "Failed to serialize item: {e}"
+                        )))
+                    }
+                },
+                Some(Err(e)) => {
+                    state.done = true;
+                    state.error_chunk(e.into())
+                }
+                None => {
+                    state.done = true;
+                    state.metadata_chunk()
+                }
+            };
+            Some((Ok::<_, Infallible>(Bytes::from(chunk)), state))
+        });
+
+        (
+            [(header::CONTENT_TYPE, HeaderValue::from_static(content_type))],
+            Body::from_stream(chunks),
+        )
+            .into_response()
+    }
+}
@@ -0,0 +1,98 @@
+// Copyright (C) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Response styles for paginated responses.
+
+use axum::Json;
+use axum::response::{IntoResponse, Response};
+use http::{HeaderMap, HeaderName, HeaderValue};
+use serde::Serialize;
+
+use super::{EnvelopeFields, PaginatedResponse};
+
+/// `X-Total-Count` header: total number of items across all pages.
+pub const X_TOTAL_COUNT: HeaderName = HeaderName::from_static("x-total-count");
+
+/// `X-Offset` header: number of items skipped.
+pub const X_OFFSET: HeaderName = HeaderName::from_static("x-offset");
+
+/// `X-Limit` header: limit per page.
+pub const X_LIMIT: HeaderName = HeaderName::from_static("x-limit");
+
+/// How a [`PaginatedResponse`] is rendered into an HTTP response.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum PaginationStyle {
+    /// A JSON object with `data`, `total`, `limit`, `offset` and `has_more` fields.
+    #[default]
+    Envelope,
+
+    /// A JSON object like [`PaginationStyle::Envelope`], with custom field names.
+    NamedEnvelope(EnvelopeFields),
+
+    /// A bare JSON array of items, with pagination metadata in the
+    /// `X-Total-Count`, `X-Offset` and `X-Limit` headers.
+    Headers,
+}
+
+impl<T> PaginatedResponse<T> {
+    /// Returns the `X-Total-Count`, `X-Offset` and `X-Limit` (if set) headers for this page.
+    ///
+    /// `X-Total-Count` is only included when the total is exact.
+    pub fn pagination_headers(&self) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        if self.is_total_exact() {
+            headers.insert(X_TOTAL_COUNT, HeaderValue::from(self.total));
+        }
+        headers.insert(X_OFFSET, HeaderValue::from(self.offset));
+        if let Some(limit) = self.limit {
+            headers.insert(X_LIMIT, HeaderValue::from(limit));
+        }
+        headers
+    }
+
+    /// Converts this page into a response using the given style.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// use bel7_axum::{PaginatedResponse, PaginationQuery, PaginationStyle};
+    ///
+    /// async fn list_items(Query(q): Query<PaginationQuery>) -> Response {
+    ///     let page = fetch_page(&q).await;
+    ///     page.into_response_with(PaginationStyle::Headers)
+    /// }
+    /// ```
+    pub fn into_response_with(self, style: PaginationStyle) -> Response
+    where
+        T: Serialize,
+    {
+        match style {
+            PaginationStyle::Envelope => Json(self).into_response(),
+            PaginationStyle::NamedEnvelope(fields) => {
+                Json(self.with_fields(fields)).into_response()
+            }
+            PaginationStyle::Headers => {
+                let headers = self.pagination_headers();
+                (headers, Json(self.data)).into_response()
+            }
+        }
+    }
+}
+
+/// Renders the page as an enveloped JSON object ([`PaginationStyle::Envelope`]).
+impl<T: Serialize> IntoResponse for PaginatedResponse<T> {
+    fn into_response(self) -> Response {
+        self.into_response_with(PaginationStyle::Envelope)
+    }
+}
@@ -0,0 +1,83 @@
+// Copyright (C) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Client-side auto-pagination of endpoints that return [`PaginatedResponse`].
+
+use std::future::Future;
+
+use futures_util::stream::{self, Stream, StreamExt};
+
+use super::paginator::{flatten_page, offset_pages};
+use super::{PaginatedResponse, PaginationQuery};
+
+/// Walks all pages of a paginated API, starting from the given query.
+///
+/// `fetch` performs the actual request and can use any transport
+/// (an HTTP client, an in-process [`Router`](axum::Router), a mock).
+/// Every request uses the limit of `start`; the offset is advanced by
+/// the number of items returned. If `start` has no limit, none is sent
+/// and the server's default page size applies.
+///
+/// The stream ends after a page with `has_more == false`, an empty page or an error.
+///
+/// # Example
+///
+/// ```ignore
+/// use bel7_axum::{PaginatedResponse, PaginationQuery, walk_pages};
+///
+/// let start = PaginationQuery { limit: Some(100), offset: None };
+/// let users = walk_pages(start, |q| {
+///     let client = client.clone();
+///     async move {
+///         client
+///             .get("https://api.example.com/users")
+///             .query(&[("limit", q.effective_limit(100)), ("offset", q.effective_offset())])
+///             .send()
+///             .await?
+///             .json::<PaginatedResponse<User>>()
+///             .await
+///     }
+/// });
+/// ```
+pub fn walk_pages<T, E, F, Fut>(
+    start: PaginationQuery,
+    fetch: F,
+) -> impl Stream<Item = Result<T, E>>
+where
+    F: FnMut(PaginationQuery) -> Fut,
+    Fut: Future<Output = Result<PaginatedResponse<T>, E>>,
+{
+    walk_page_responses(start, fetch)
+        .flat_map(|page| stream::iter(flatten_page(page.map(|p| p.data))))
+}
+
+/// Like [`walk_pages`], but yields whole pages instead of individual items.
+pub fn walk_page_responses<T, E, F, Fut>(
+    start: PaginationQuery,
+    fetch: F,
+) -> impl Stream<Item = Result<PaginatedResponse<T>, E>>
+where
+    F: FnMut(PaginationQuery) -> Fut,
+    Fut: Future<Output = Result<PaginatedResponse<T>, E>>,
+{
+    offset_pages(
+        fetch,
+        start.limit,
+        start.effective_offset(),
+        |mut fetch, query| async move {
+            let result = fetch(query).await;
+            (fetch, result)
+        },
+    )
+}
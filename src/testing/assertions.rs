@@ -0,0 +1,106 @@
+// Copyright (C) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Test helpers for asserting on error and paginated responses.
+//!
+//! These helpers panic with a descriptive message on mismatch
+//! and are meant to be used in integration tests.
+
+use axum::response::Response;
+use http::StatusCode;
+use serde::de::DeserializeOwned;
+
+use crate::{ErrorResponse, PaginatedResponse};
+
+/// Reads the response body and deserializes it from JSON.
+///
+/// # Panics
+///
+/// Panics if the body cannot be read or is not valid JSON for `T`.
+pub async fn read_json<T: DeserializeOwned>(response: Response) -> T {
+    let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .expect("failed to read response body");
+    serde_json::from_slice(&bytes).unwrap_or_else(|e| {
+        panic!(
+            "failed to decode response body: {e}; body: {}",
+            String::from_utf8_lossy(&bytes)
+        )
+    })
+}
+
+/// Reads the status code and [`ErrorResponse`] body of a response.
+///
+/// # Panics
+///
+/// Panics if the body is not a valid [`ErrorResponse`].
+pub async fn read_error_response(response: Response) -> (StatusCode, ErrorResponse) {
+    let status = response.status();
+    (status, read_json(response).await)
+}
+
+/// Asserts that a response is an error with the given status and details.
+///
+/// Pass `None` as `details` to assert that details are absent,
+/// e.g. for [`ApiError::Internal`](crate::ApiError::Internal).
+///
+/// # Example
+///
+/// ```ignore
+/// use bel7_axum::assert_api_error;
+///
+/// let response = app.oneshot(request).await.unwrap();
+/// assert_api_error(response, StatusCode::NOT_FOUND, Some("User 7 not found")).await;
+/// ```
+pub async fn assert_api_error(
+    response: Response,
+    status: StatusCode,
+    details: Option<&str>,
+) -> ErrorResponse {
+    let (actual_status, body) = read_error_response(response).await;
+    assert_eq!(
+        actual_status, status,
+        "unexpected status code, body: {body:?}"
+    );
+    assert_eq!(
+        body.details.as_deref(),
+        details,
+        "unexpected error details for {status}"
+    );
+    body
+}
+
+/// Decodes a [`PaginatedResponse`] and asserts its `total`, `has_more` and `offset`.
+///
+/// Returns the decoded response for further assertions on `data`.
+///
+/// # Panics
+///
+/// Panics if the status is not successful, the body cannot be decoded
+/// or any of the values differ.
+pub async fn assert_paginated<T: DeserializeOwned>(
+    response: Response,
+    total: u64,
+    has_more: bool,
+    offset: u64,
+) -> PaginatedResponse<T> {
+    let status = response.status();
+    assert!(status.is_success(), "unexpected status code {status}");
+
+    let page: PaginatedResponse<T> = read_json(response).await;
+    assert_eq!(page.total, total, "unexpected total");
+    assert_eq!(page.has_more, has_more, "unexpected has_more");
+    assert_eq!(page.offset, offset, "unexpected offset");
+    page
+}
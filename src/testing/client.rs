@@ -0,0 +1,249 @@
+// Copyright (C) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! In-process test client.
+
+use std::io;
+use std::net::SocketAddr;
+
+use axum::Router;
+use axum::body::Body;
+use axum::response::Response;
+use http::{Method, Request, StatusCode, Uri, header};
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use thiserror::Error;
+use tokio::net::{TcpListener, TcpStream};
+use tokio::task::JoinHandle;
+use tokio_tungstenite::{MaybeTlsStream, WebSocketStream};
+use tower::ServiceExt;
+
+use crate::pagination::uri_with_pagination;
+use crate::{ApiError, ErrorResponse, IntoApiError, PaginatedResponse};
+
+/// An error response received by a [`TestClient`].
+///
+/// `status` is the status code of the response. `error` is the closest
+/// [`ApiError`] variant: statuses without a variant of their own
+/// (e.g. `405` or `429`) map to [`ApiError::BadRequest`] or
+/// [`ApiError::Internal`], so assertions on such statuses should check `status`.
+#[derive(Error, Debug, Clone)]
+#[error("{status}: {error}")]
+pub struct TestClientError {
+    /// The response status code.
+    pub status: StatusCode,
+    /// The decoded error.
+    pub error: ApiError,
+}
+
+impl TestClientError {
+    fn local(error: ApiError) -> Self {
+        Self {
+            status: error.status_code(),
+            error,
+        }
+    }
+}
+
+impl IntoApiError for TestClientError {
+    fn into_api_error(self) -> ApiError {
+        self.error
+    }
+}
+
+/// A WebSocket connection opened by [`TestServer::ws`].
+pub type TestWebSocket = WebSocketStream<MaybeTlsStream<TcpStream>>;
+
+/// An in-process client for a [`Router`].
+///
+/// Requests are dispatched with `tower::ServiceExt::oneshot`, without
+/// binding a socket. Error responses are decoded into a [`TestClientError`].
+///
+/// # Example
+///
+/// ```ignore
+/// use bel7_axum::TestClient;
+///
+/// let client = TestClient::new(app());
+/// let user: User = client.get("/users/7").await?;
+/// let err = client.get::<User>("/users/0").await.unwrap_err();
+/// assert_eq!(err.status, StatusCode::NOT_FOUND);
+/// assert!(matches!(err.error, ApiError::NotFound(_)));
+/// ```
+#[derive(Debug, Clone)]
+pub struct TestClient {
+    router: Router,
+}
+
+impl TestClient {
+    /// Creates a client for the given router.
+    pub fn new(router: Router) -> Self {
+        Self { router }
+    }
+
+    /// Dispatches a request and returns the raw response.
+    pub async fn request(&self, request: Request<Body>) -> Response {
+        // The error type of Router is Infallible
+        match self.router.clone().oneshot(request).await {
+            Ok(response) => response,
+            Err(never) => match never {},
+        }
+    }
+
+    /// Issues a `GET` request and decodes the JSON response body.
+    pub async fn get<T: DeserializeOwned>(&self, uri: &str) -> Result<T, TestClientError> {
+        let request = Request::builder()
+            .method(Method::GET)
+            .uri(uri)
+            .body(Body::empty())
+            .map_err(|e| TestClientError::local(ApiError::BadRequest(e.to_string())))?;
+        decode_response(self.request(request).await).await
+    }
+
+    /// Issues a `POST` request with a JSON body and decodes the JSON response body.
+    pub async fn post_json<B, T>(&self, uri: &str, body: &B) -> Result<T, TestClientError>
+    where
+        B: Serialize + ?Sized,
+        T: DeserializeOwned,
+    {
+        let json = serde_json::to_vec(body)
+            .map_err(|e| TestClientError::local(ApiError::BadRequest(e.to_string())))?;
+        let request = Request::builder()
+            .method(Method::POST)
+            .uri(uri)
+            .header(header::CONTENT_TYPE, "application/json")
+            .body(Body::from(json))
+            .map_err(|e| TestClientError::local(ApiError::BadRequest(e.to_string())))?;
+        decode_response(self.request(request).await).await
+    }
+
+    /// Fetches every page of a paginated endpoint and returns all items.
+    ///
+    /// Pages are requested with the given `limit` and an increasing `offset`
+    /// until the response has `has_more == false` or comes back empty.
+    pub async fn get_all_pages<T: DeserializeOwned>(
+        &self,
+        uri: &str,
+        limit: u64,
+    ) -> Result<Vec<T>, TestClientError> {
+        let uri = Uri::try_from(uri)
+            .map_err(|e| TestClientError::local(ApiError::BadRequest(e.to_string())))?;
+        let mut items = Vec::new();
+        let mut offset = 0;
+        loop {
+            let page: PaginatedResponse<T> =
+                self.get(&uri_with_pagination(&uri, limit, offset)).await?;
+            let returned = page.data.len() as u64;
+            items.extend(page.data);
+            if !page.has_more || returned == 0 {
+                return Ok(items);
+            }
+            offset += returned;
+        }
+    }
+
+    /// Binds the router to a local port, e.g. to open WebSocket connections.
+    pub async fn serve(&self) -> io::Result<TestServer> {
+        TestServer::start(self.router.clone()).await
+    }
+}
+
+/// A router served on a local, ephemeral port.
+///
+/// The server is shut down when this value is dropped.
+#[derive(Debug)]
+pub struct TestServer {
+    addr: SocketAddr,
+    handle: JoinHandle<()>,
+}
+
+impl TestServer {
+    /// Starts serving the router on `127.0.0.1` with an ephemeral port.
+    pub async fn start(router: Router) -> io::Result<Self> {
+        let listener = TcpListener::bind("127.0.0.1:0").await?;
+        let addr = listener.local_addr()?;
+        let handle = tokio::spawn(async move {
+            let _ = axum::serve(listener, router).await;
+        });
+        Ok(Self { addr, handle })
+    }
+
+    /// Returns the address the server is bound to.
+    pub fn addr(&self) -> SocketAddr {
+        self.addr
+    }
+
+    /// Returns a `http://` URL for the given path.
+    pub fn url(&self, path: &str) -> String {
+        format!("http://{}{}", self.addr, path)
+    }
+
+    /// Opens a WebSocket connection to the given path.
+    pub async fn ws(
+        &self,
+        path: &str,
+    ) -> Result<TestWebSocket, tokio_tungstenite::tungstenite::Error> {
+        let url = format!("ws://{}{}", self.addr, path);
+        let (stream, _) = tokio_tungstenite::connect_async(url).await?;
+        Ok(stream)
+    }
+}
+
+impl Drop for TestServer {
+    fn drop(&mut self) {
+        self.handle.abort();
+    }
+}
+
+async fn decode_response<T: DeserializeOwned>(response: Response) -> Result<T, TestClientError> {
+    let status = response.status();
+    let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .map_err(|e| TestClientError {
+            status,
+            error: ApiError::Internal(e.to_string()),
+        })?;
+
+    if status.is_success() {
+        return serde_json::from_slice(&bytes).map_err(|e| TestClientError {
+            status,
+            error: ApiError::Internal(format!(
+                "failed to decode response body: {e}; body: {}",
+                String::from_utf8_lossy(&bytes)
+            )),
+        });
+    }
+
+    let details = match serde_json::from_slice::<ErrorResponse>(&bytes) {
+        Ok(body) => body.details.unwrap_or(body.error),
+        Err(_) => String::from_utf8_lossy(&bytes).into_owned(),
+    };
+    Err(TestClientError {
+        status,
+        error: api_error_from_status(status, details),
+    })
+}
+
+fn api_error_from_status(status: StatusCode, details: String) -> ApiError {
+    match status {
+        StatusCode::UNAUTHORIZED => ApiError::Unauthorized(details),
+        StatusCode::FORBIDDEN => ApiError::Forbidden(details),
+        StatusCode::NOT_FOUND => ApiError::NotFound(details),
+        StatusCode::CONFLICT => ApiError::Conflict(details),
+        StatusCode::UNPROCESSABLE_ENTITY => ApiError::ValidationError(details),
+        StatusCode::SERVICE_UNAVAILABLE => ApiError::ServiceUnavailable(details),
+        s if s.is_server_error() => ApiError::Internal(details),
+        _ => ApiError::BadRequest(details),
+    }
+}
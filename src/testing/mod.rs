@@ -0,0 +1,27 @@
+// Copyright (C) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Utilities for testing routers built with this crate.
+//!
+//! This module provides:
+//! - Assertion helpers such as [`assert_api_error`] and [`assert_paginated`]
+//! - [`TestClient`] - An in-process client for a [`Router`](axum::Router)
+//! - [`TestClientError`] - Error responses received by a [`TestClient`], with their status code
+//! - [`TestServer`] - A locally bound server for WebSocket tests
+
+mod assertions;
+mod client;
+
+pub use assertions::*;
+pub use client::*;
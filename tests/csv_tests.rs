@@ -0,0 +1,239 @@
+// Copyright (C) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use axum::Router;
+use axum::body::Body;
+use axum::response::Response;
+use axum::routing::get;
+use bel7_axum::{
+    ApiError, CSV_CONTENT_TYPE, PaginatedResponse, PaginationQuery, ResponseFormat, X_LIMIT,
+    X_OFFSET, X_TOTAL_COUNT,
+};
+use http::{Request, StatusCode, header};
+use serde::Serialize;
+use tower::ServiceExt;
+
+#[derive(Clone, Serialize)]
+struct User {
+    id: u32,
+    name: &'static str,
+}
+
+const HEADERS: &[&str] = &["id", "name"];
+
+const USERS: [User; 3] = [
+    User {
+        id: 1,
+        name: "alice",
+    },
+    User {
+        id: 2,
+        name: "bob, jr.",
+    },
+    User {
+        id: 3,
+        name: "carol",
+    },
+];
+
+async fn list(format: ResponseFormat) -> Response {
+    let query = PaginationQuery {
+        limit: Some(2),
+        offset: None,
+    };
+    PaginatedResponse::from_slice(&USERS, &query, 10).into_response_as(format, HEADERS)
+}
+
+async fn call(uri: &str, accept: Option<&str>) -> Response {
+    let app = Router::new().route("/users", get(list));
+    let mut request = Request::builder().uri(uri);
+    if let Some(accept) = accept {
+        request = request.header(header::ACCEPT, accept);
+    }
+    app.oneshot(request.body(Body::empty()).unwrap())
+        .await
+        .unwrap()
+}
+
+async fn body_string(response: Response) -> String {
+    let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    String::from_utf8(bytes.to_vec()).unwrap()
+}
+
+#[test]
+fn test_from_accept() {
+    assert_eq!(ResponseFormat::from_accept("text/csv"), ResponseFormat::Csv);
+    assert_eq!(
+        ResponseFormat::from_accept("application/json, text/csv"),
+        ResponseFormat::Json
+    );
+    assert_eq!(
+        ResponseFormat::from_accept("application/json;q=0.5, text/csv;q=0.9"),
+        ResponseFormat::Csv
+    );
+    assert_eq!(
+        ResponseFormat::from_accept("text/csv;q=0, */*"),
+        ResponseFormat::Json
+    );
+}
+
+#[tokio::test]
+async fn test_csv_response() {
+    let response = call("/users?format=csv", None).await;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.headers()[header::CONTENT_TYPE], CSV_CONTENT_TYPE);
+    assert_eq!(response.headers()[X_TOTAL_COUNT], "3");
+    assert_eq!(response.headers()[X_OFFSET], "0");
+    assert_eq!(response.headers()[X_LIMIT], "2");
+    assert_eq!(
+        body_string(response).await,
+        "id,name\n1,alice\n2,\"bob, jr.\"\n"
+    );
+
+    let response = call("/users", Some("text/csv")).await;
+    assert_eq!(response.headers()[header::CONTENT_TYPE], CSV_CONTENT_TYPE);
+}
+
+#[tokio::test]
+async fn test_format_selection() {
+    let response = call("/users", None).await;
+    assert_eq!(response.headers()[header::CONTENT_TYPE], "application/json");
+
+    // the query parameter takes precedence over Accept
+    let response = call("/users?format=json", Some("text/csv")).await;
+    assert_eq!(response.headers()[header::CONTENT_TYPE], "application/json");
+
+    let response = call("/users?format=xml", None).await;
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn test_unsupported_records() {
+    #[derive(Serialize)]
+    struct Tagged {
+        id: u32,
+        tags: Vec<&'static str>,
+    }
+
+    let page = PaginatedResponse::single_page(vec![Tagged {
+        id: 1,
+        tags: vec!["a", "b"],
+    }]);
+    assert!(matches!(
+        page.to_csv(&["id", "tags"]),
+        Err(ApiError::Internal(_))
+    ));
+    assert_eq!(
+        page.into_csv_response(&["id", "tags"]).status(),
+        StatusCode::INTERNAL_SERVER_ERROR
+    );
+}
+
+#[test]
+fn test_header_row_for_empty_page() {
+    let page = PaginatedResponse::<User>::single_page(vec![]);
+    assert_eq!(page.to_csv(HEADERS).unwrap(), b"id,name\n");
+
+    // headers must match the records
+    let page = PaginatedResponse::single_page(USERS.to_vec());
+    assert!(matches!(page.to_csv(&["id"]), Err(ApiError::Internal(_))));
+}
+
+#[test]
+fn test_headers_must_match_field_names() {
+    let page = PaginatedResponse::single_page(USERS[..1].to_vec());
+    assert!(matches!(
+        page.to_csv(&["name", "id"]),
+        Err(ApiError::Internal(_))
+    ));
+    assert!(matches!(
+        page.to_csv(&["id", "title"]),
+        Err(ApiError::Internal(_))
+    ));
+
+    // tuples have no field names, the given headers are used
+    let page = PaginatedResponse::single_page(vec![(1, "alice")]);
+    assert_eq!(page.to_csv(&["id", "name"]).unwrap(), b"id,name\n1,alice\n");
+}
+
+#[test]
+fn test_formula_injection_is_neutralised() {
+    #[derive(Serialize)]
+    struct Row {
+        name: &'static str,
+        balance: i64,
+    }
+
+    let page = PaginatedResponse::single_page(vec![
+        Row {
+            name: "=HYPERLINK(\"http://evil\")",
+            balance: -5,
+        },
+        Row {
+            name: "@SUM(A1)",
+            balance: 3,
+        },
+        Row {
+            name: "-2+3",
+            balance: 0,
+        },
+    ]);
+    let csv = String::from_utf8(page.to_csv(&["name", "balance"]).unwrap()).unwrap();
+    assert_eq!(
+        csv,
+        "name,balance\n\"'=HYPERLINK(\"\"http://evil\"\")\",-5\n'@SUM(A1),3\n'-2+3,0\n"
+    );
+}
+
+#[cfg(feature = "stream")]
+#[tokio::test]
+async fn test_csv_stream() {
+    use axum::response::IntoResponse;
+    use bel7_axum::CsvStreamResponse;
+    use futures_util::stream;
+
+    let users = stream::iter(USERS.to_vec().into_iter().map(Ok::<_, ApiError>));
+    let response = CsvStreamResponse::new(users, HEADERS)
+        .with_total(3)
+        .into_response();
+    assert_eq!(response.headers()[header::CONTENT_TYPE], CSV_CONTENT_TYPE);
+    assert_eq!(response.headers()[X_TOTAL_COUNT], "3");
+    assert_eq!(
+        body_string(response).await,
+        "id,name\n1,alice\n2,\"bob, jr.\"\n3,carol\n"
+    );
+
+    // header rows built at runtime
+    let columns: Vec<String> = HEADERS.iter().map(|h| h.to_string()).collect();
+    let users = stream::iter(USERS[..1].to_vec().into_iter().map(Ok::<_, ApiError>));
+    let response = CsvStreamResponse::new(users, columns).into_response();
+    assert_eq!(body_string(response).await, "id,name\n1,alice\n");
+
+    let empty = stream::iter(Vec::<Result<User, ApiError>>::new());
+    let response = CsvStreamResponse::new(empty, HEADERS).into_response();
+    assert_eq!(body_string(response).await, "id,name\n");
+
+    let failing = stream::iter(vec![
+        Ok(USERS[0].clone()),
+        Err(ApiError::Internal("connection reset".into())),
+    ]);
+    let response = CsvStreamResponse::new(failing, HEADERS).into_response();
+    assert!(
+        axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .is_err()
+    );
+}
@@ -0,0 +1,162 @@
+// Copyright (C) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::time::Duration;
+
+use bel7_axum::{ApiError, CursorCodec, CursorPaginatedResponse, CursorQuery};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Key {
+    created_at: String,
+    id: u64,
+}
+
+fn key() -> Key {
+    Key {
+        created_at: "2025-06-01T12:00:00Z".into(),
+        id: 42,
+    }
+}
+
+#[test]
+fn test_unsigned_roundtrip() {
+    let codec = CursorCodec::new();
+    let cursor = codec.encode(&key()).unwrap();
+    assert!(!cursor.contains('.'));
+    assert!(
+        cursor
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+    );
+    assert_eq!(codec.decode::<Key>(&cursor).unwrap(), key());
+}
+
+#[test]
+fn test_signed_roundtrip() {
+    let codec = CursorCodec::signed("s3cr3t");
+    let cursor = codec.encode(&key()).unwrap();
+    assert_eq!(codec.decode::<Key>(&cursor).unwrap(), key());
+}
+
+#[test]
+fn test_tampered_payload_is_rejected() {
+    let codec = CursorCodec::signed("s3cr3t");
+    let cursor = codec.encode(&key()).unwrap();
+    let (_, signature) = cursor.split_once('.').unwrap();
+
+    let forged_payload = CursorCodec::new()
+        .encode(&Key {
+            created_at: "2025-06-01T12:00:00Z".into(),
+            id: 1,
+        })
+        .unwrap();
+    let forged = format!("{forged_payload}.{signature}");
+    assert!(matches!(
+        codec.decode::<Key>(&forged),
+        Err(ApiError::BadRequest(_))
+    ));
+}
+
+#[test]
+fn test_unsigned_cursor_rejected_by_signed_codec() {
+    let unsigned = CursorCodec::new().encode(&key()).unwrap();
+    let err = CursorCodec::signed("s3cr3t")
+        .decode::<Key>(&unsigned)
+        .unwrap_err();
+    assert!(matches!(err, ApiError::BadRequest(ref msg) if msg == "Invalid cursor"));
+}
+
+#[test]
+fn test_different_secret_is_rejected() {
+    let cursor = CursorCodec::signed("one").encode(&key()).unwrap();
+    assert!(CursorCodec::signed("two").decode::<Key>(&cursor).is_err());
+}
+
+#[test]
+fn test_expired_cursor_is_rejected() {
+    let codec = CursorCodec::signed("s3cr3t").with_ttl(Duration::ZERO);
+    let cursor = codec.encode(&key()).unwrap();
+    let err = codec.decode::<Key>(&cursor).unwrap_err();
+    assert!(matches!(err, ApiError::BadRequest(ref msg) if msg == "Cursor has expired"));
+
+    let codec = CursorCodec::signed("s3cr3t").with_ttl(Duration::from_secs(60));
+    let cursor = codec.encode(&key()).unwrap();
+    assert_eq!(codec.decode::<Key>(&cursor).unwrap(), key());
+}
+
+#[test]
+fn test_sub_second_ttl() {
+    let codec = CursorCodec::signed("s3cr3t").with_ttl(Duration::from_millis(500));
+    let cursor = codec.encode(&key()).unwrap();
+    assert_eq!(codec.decode::<Key>(&cursor).unwrap(), key());
+}
+
+#[test]
+fn test_ttl_requires_secret() {
+    let codec = CursorCodec::new().with_ttl(Duration::from_secs(60));
+    assert!(matches!(codec.encode(&key()), Err(ApiError::Internal(_))));
+}
+
+#[test]
+fn test_garbage_is_rejected() {
+    let codec = CursorCodec::new();
+    assert!(codec.decode::<Key>("not a cursor!").is_err());
+    assert!(codec.decode::<Key>("").is_err());
+}
+
+#[test]
+fn test_decode_query() {
+    let codec = CursorCodec::new();
+    assert_eq!(
+        codec.decode_query::<u64>(&CursorQuery::default()).unwrap(),
+        None
+    );
+
+    let query = CursorQuery {
+        cursor: Some(codec.encode(&7u64).unwrap()),
+        before: None,
+        limit: None,
+    };
+    assert_eq!(codec.decode_query::<u64>(&query).unwrap(), Some(7));
+}
+
+#[test]
+fn test_debug_does_not_leak_secret() {
+    let codec = CursorCodec::signed("very-secret-value");
+    assert!(!format!("{codec:?}").contains("very-secret-value"));
+}
+
+#[test]
+fn test_from_probe_encoded() {
+    let codec = CursorCodec::signed("s3cr3t");
+    let query = CursorQuery {
+        cursor: Some(codec.encode(&("2025-01-01", 3u64)).unwrap()),
+        before: None,
+        limit: Some(2),
+    };
+    let rows = vec![
+        ("2025-01-01", 4u64),
+        ("2025-01-02", 1u64),
+        ("2025-01-02", 5u64),
+    ];
+    let page =
+        CursorPaginatedResponse::from_probe_encoded(rows, 2, &query, &codec, |row| *row).unwrap();
+
+    assert!(page.has_more);
+    let next: (String, u64) = codec.decode(page.next_cursor.as_deref().unwrap()).unwrap();
+    assert_eq!(next, ("2025-01-02".to_string(), 1));
+    let prev: (String, u64) = codec.decode(page.prev_cursor.as_deref().unwrap()).unwrap();
+    assert_eq!(prev, ("2025-01-01".to_string(), 4));
+}
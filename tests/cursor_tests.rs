@@ -0,0 +1,87 @@
+// Copyright (C) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use bel7_axum::{CursorPaginatedResponse, CursorQuery};
+
+#[derive(Debug, Clone, PartialEq)]
+struct Row {
+    id: u64,
+}
+
+fn rows(ids: &[u64]) -> Vec<Row> {
+    ids.iter().map(|&id| Row { id }).collect()
+}
+
+#[test]
+fn test_from_items_first_page() {
+    let query = CursorQuery::default();
+    let resp = CursorPaginatedResponse::from_items(rows(&[1, 2, 3]), true, &query, |r| r.id);
+    assert_eq!(resp.next_cursor.as_deref(), Some("3"));
+    assert_eq!(resp.prev_cursor, None);
+    assert!(resp.has_more);
+}
+
+#[test]
+fn test_from_items_last_page() {
+    let query = CursorQuery {
+        cursor: Some("3".into()),
+        before: None,
+        limit: Some(3),
+    };
+    let resp = CursorPaginatedResponse::from_items(rows(&[4, 5]), false, &query, |r| r.id);
+    assert_eq!(resp.next_cursor, None);
+    assert_eq!(resp.prev_cursor.as_deref(), Some("4"));
+    assert!(!resp.has_more);
+}
+
+#[test]
+fn test_from_items_backward() {
+    // the client sent prev_cursor "4" of the page [4, 5] as `before`
+    let query: CursorQuery = serde_json::from_str(r#"{"before": "4", "limit": 2}"#).unwrap();
+    assert!(query.is_backward());
+    assert_eq!(query.position(), Some("4"));
+
+    let resp = CursorPaginatedResponse::from_items(rows(&[2, 3]), true, &query, |r| r.id);
+    assert_eq!(resp.prev_cursor.as_deref(), Some("2"));
+    assert_eq!(resp.next_cursor.as_deref(), Some("3"));
+    assert!(resp.has_more);
+
+    // the first page, reached backwards
+    let resp = CursorPaginatedResponse::from_items(rows(&[1]), false, &query, |r| r.id);
+    assert_eq!(resp.prev_cursor, None);
+    assert_eq!(resp.next_cursor.as_deref(), Some("1"));
+}
+
+#[test]
+fn test_cursor_serialization_omits_missing_cursors() {
+    let resp = CursorPaginatedResponse::single_page(vec![1, 2]);
+    let json = serde_json::to_value(&resp).unwrap();
+    assert_eq!(json, serde_json::json!({"data": [1, 2], "has_more": false}));
+}
+
+#[test]
+fn test_cursor_map() {
+    let resp = CursorPaginatedResponse::new(vec![1, 2], Some("b".into()), None, true);
+    let mapped = resp.map(|x| x * 10);
+    assert_eq!(mapped.data, vec![10, 20]);
+    assert_eq!(mapped.next_cursor.as_deref(), Some("b"));
+}
+
+#[test]
+fn test_cursor_query_limit() {
+    let q: CursorQuery = serde_json::from_str(r#"{"cursor": "abc", "limit": 500}"#).unwrap();
+    assert_eq!(q.effective_limit(100), 100);
+    assert_eq!(q.cursor.as_deref(), Some("abc"));
+    assert_eq!(CursorQuery::default().effective_limit(25), 25);
+}
@@ -0,0 +1,131 @@
+// Copyright (C) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use axum::body::Body;
+use axum::routing::get;
+use axum::{Router, middleware};
+use bel7_axum::{
+    ApiError, ErrorCatalog, SharedErrorCatalog, StaticCatalog, accepted_languages, localize_errors,
+};
+use http::{Request, header};
+use tower::ServiceExt;
+
+fn app() -> Router {
+    let catalog: SharedErrorCatalog = Arc::new(
+        StaticCatalog::new()
+            .with_label("de", "not-found", "Nicht gefunden")
+            .with_message("de", "not-found", "Nicht gefunden: {details}")
+            .with_label("fr", "not-found", "Introuvable"),
+    );
+
+    Router::new()
+        .route(
+            "/missing",
+            get(|| async { Err::<(), _>(ApiError::NotFound("user 7".into())) }),
+        )
+        .route(
+            "/conflict",
+            get(|| async { Err::<(), _>(ApiError::Conflict("taken".into())) }),
+        )
+        .layer(middleware::from_fn_with_state(catalog, localize_errors))
+}
+
+async fn get_json(uri: &str, accept_language: Option<&str>) -> (Option<String>, serde_json::Value) {
+    let mut request = Request::builder().uri(uri);
+    if let Some(lang) = accept_language {
+        request = request.header(header::ACCEPT_LANGUAGE, lang);
+    }
+    let response = app()
+        .oneshot(request.body(Body::empty()).unwrap())
+        .await
+        .unwrap();
+    let content_language = response
+        .headers()
+        .get(header::CONTENT_LANGUAGE)
+        .map(|v| v.to_str().unwrap().to_string());
+    let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    (content_language, serde_json::from_slice(&bytes).unwrap())
+}
+
+#[test]
+fn test_accepted_languages_ordering() {
+    assert_eq!(
+        accepted_languages("en;q=0.5, de-AT, fr;q=0.7, es;q=0"),
+        vec!["de-AT", "fr", "en"]
+    );
+    assert!(accepted_languages("").is_empty());
+}
+
+#[test]
+fn test_static_catalog_lookup_is_case_insensitive() {
+    let catalog = StaticCatalog::new().with_label("pt-BR", "conflict", "Conflito");
+    assert_eq!(
+        catalog.label("pt-br", "conflict").as_deref(),
+        Some("Conflito")
+    );
+    assert_eq!(catalog.label("pt", "conflict"), None);
+}
+
+#[tokio::test]
+async fn test_localized_label_and_message() {
+    let (lang, body) = get_json("/missing", Some("de-AT, en;q=0.5")).await;
+    assert_eq!(lang.as_deref(), Some("de"));
+    assert_eq!(body["error"], "Nicht gefunden");
+    assert_eq!(body["details"], "Nicht gefunden: user 7");
+}
+
+#[tokio::test]
+async fn test_label_only_translation_keeps_details() {
+    let (lang, body) = get_json("/missing", Some("fr")).await;
+    assert_eq!(lang.as_deref(), Some("fr"));
+    assert_eq!(body["error"], "Introuvable");
+    assert_eq!(body["details"], "user 7");
+}
+
+#[tokio::test]
+async fn test_english_fallback() {
+    let (lang, body) = get_json("/conflict", Some("de")).await;
+    assert_eq!(lang, None);
+    assert_eq!(body["error"], "Conflict");
+
+    let (_, body) = get_json("/missing", None).await;
+    assert_eq!(body["error"], "Not Found");
+}
+
+#[tokio::test]
+async fn test_vary_accept_language() {
+    let vary = |accept_language: Option<&'static str>| async move {
+        let mut request = Request::builder().uri("/conflict");
+        if let Some(lang) = accept_language {
+            request = request.header(header::ACCEPT_LANGUAGE, lang);
+        }
+        let response = app()
+            .oneshot(request.body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        response
+            .headers()
+            .get(header::VARY)
+            .map(|v| v.to_str().unwrap().to_string())
+    };
+
+    // untranslated responses vary too, other languages may have a translation
+    assert_eq!(vary(Some("de")).await.as_deref(), Some("accept-language"));
+    // the English fallback depends on the missing header as well
+    assert_eq!(vary(None).await.as_deref(), Some("accept-language"));
+}
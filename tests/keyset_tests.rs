@@ -0,0 +1,97 @@
+// Copyright (C) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use bel7_axum::{ApiError, CursorPaginatedResponse, CursorQuery, PlaceholderStyle, SortSpec};
+
+const FIELDS: &[&str] = &["a", "b", "c"];
+
+#[test]
+fn test_single_field() {
+    let spec = SortSpec::parse("-a", FIELDS).unwrap();
+    let p = spec
+        .keyset_predicate(&[5], PlaceholderStyle::QuestionMark)
+        .unwrap();
+    assert_eq!(p.sql, "a < ?");
+    assert_eq!(p.params, vec![5]);
+}
+
+#[test]
+fn test_uniform_directions_use_row_values() {
+    let spec = SortSpec::parse("a,b", FIELDS).unwrap();
+    let p = spec
+        .keyset_predicate(&[1, 2], PlaceholderStyle::QuestionMark)
+        .unwrap();
+    assert_eq!(p.sql, "(a, b) > (?, ?)");
+    assert_eq!(p.params, vec![1, 2]);
+
+    let p = spec
+        .reversed()
+        .keyset_predicate(&[1, 2], PlaceholderStyle::Numbered(3))
+        .unwrap();
+    assert_eq!(p.sql, "(a, b) < ($3, $4)");
+}
+
+#[test]
+fn test_mixed_directions_expand() {
+    let spec = SortSpec::parse("a,-b,c", FIELDS).unwrap();
+    let p = spec
+        .keyset_predicate(&[1, 2, 3], PlaceholderStyle::QuestionMark)
+        .unwrap();
+    assert_eq!(
+        p.sql,
+        "(a > ? OR (a = ? AND b < ?) OR (a = ? AND b = ? AND c > ?))"
+    );
+    assert_eq!(p.params, vec![1, 1, 2, 1, 2, 3]);
+}
+
+#[test]
+fn test_key_arity_mismatch() {
+    let spec = SortSpec::parse("a,b", FIELDS).unwrap();
+    let err = spec
+        .keyset_predicate(&[1], PlaceholderStyle::QuestionMark)
+        .unwrap_err();
+    assert!(matches!(err, ApiError::BadRequest(_)));
+    assert!(matches!(
+        SortSpec::default().keyset_predicate::<i32>(&[], PlaceholderStyle::QuestionMark),
+        Err(ApiError::Internal(_))
+    ));
+}
+
+#[test]
+fn test_from_probe_backward() {
+    // SELECT ... WHERE id < 4 ORDER BY id DESC LIMIT 3
+    let query = CursorQuery {
+        cursor: None,
+        before: Some("4".into()),
+        limit: Some(2),
+    };
+    let page = CursorPaginatedResponse::from_probe(vec![3, 2, 1], 2, &query, |id| *id);
+    assert_eq!(page.data, vec![2, 3]);
+    assert_eq!(page.prev_cursor.as_deref(), Some("2"));
+    assert_eq!(page.next_cursor.as_deref(), Some("3"));
+}
+
+#[test]
+fn test_from_probe() {
+    let query = CursorQuery {
+        cursor: Some("3".into()),
+        before: None,
+        limit: Some(2),
+    };
+    let page = CursorPaginatedResponse::from_probe(vec![4, 5], 2, &query, |id| *id);
+    assert_eq!(page.data, vec![4, 5]);
+    assert!(!page.has_more);
+    assert_eq!(page.next_cursor, None);
+    assert_eq!(page.prev_cursor.as_deref(), Some("4"));
+}
@@ -0,0 +1,131 @@
+// Copyright (C) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use bel7_axum::{PageLinks, PaginatedResponse};
+use http::{Uri, header};
+
+fn uri(s: &str) -> Uri {
+    s.parse().unwrap()
+}
+
+#[test]
+fn test_page_links_middle_page() {
+    let page = PaginatedResponse::new(vec![0; 10], 95, Some(10), 20);
+    let links = page.page_links(&uri("/items?status=open&limit=10&offset=20"));
+    assert_eq!(
+        links,
+        PageLinks {
+            first: Some("/items?status=open&limit=10&offset=0".into()),
+            prev: Some("/items?status=open&limit=10&offset=10".into()),
+            next: Some("/items?status=open&limit=10&offset=30".into()),
+            last: Some("/items?status=open&limit=10&offset=90".into()),
+        }
+    );
+}
+
+#[test]
+fn test_page_links_first_and_last_pages() {
+    let first = PaginatedResponse::new(vec![0; 10], 20, Some(10), 0);
+    let links = first.page_links(&uri("/items"));
+    assert_eq!(links.prev, None);
+    assert_eq!(links.next.as_deref(), Some("/items?limit=10&offset=10"));
+    assert_eq!(links.last.as_deref(), Some("/items?limit=10&offset=10"));
+
+    let last = PaginatedResponse::new(vec![0; 10], 20, Some(10), 10);
+    let links = last.page_links(&uri("/items"));
+    assert_eq!(links.prev.as_deref(), Some("/items?limit=10&offset=0"));
+    assert_eq!(links.next, None);
+}
+
+#[test]
+fn test_page_links_absolute_uri() {
+    let page = PaginatedResponse::new(vec![0; 5], 6, Some(5), 0);
+    let links = page.page_links(&uri("https://api.example.com/v1/items?q=a%20b"));
+    assert_eq!(
+        links.next.as_deref(),
+        Some("https://api.example.com/v1/items?q=a+b&limit=5&offset=5")
+    );
+}
+
+#[test]
+fn test_no_last_link_without_exact_total() {
+    let page = PaginatedResponse::from_probe(vec![1, 2, 3], 2, 0);
+    let links = page.page_links(&uri("/items"));
+    assert_eq!(links.next.as_deref(), Some("/items?limit=2&offset=2"));
+    assert_eq!(links.last, None);
+}
+
+#[test]
+fn test_no_links_without_limit() {
+    let page = PaginatedResponse::single_page(vec![1, 2, 3]);
+    assert_eq!(page.link_header(&uri("/items")), None);
+}
+
+#[test]
+fn test_into_response_with_links() {
+    let page = PaginatedResponse::new(vec![1, 2], 6, Some(2), 2);
+    let response = page.into_response_with_links(&uri("/items?offset=2&limit=2"));
+    let link = response
+        .headers()
+        .get(header::LINK)
+        .unwrap()
+        .to_str()
+        .unwrap();
+    assert_eq!(
+        link,
+        "</items?limit=2&offset=0>; rel=\"first\", \
+         </items?limit=2&offset=0>; rel=\"prev\", \
+         </items?limit=2&offset=4>; rel=\"next\", \
+         </items?limit=2&offset=4>; rel=\"last\""
+    );
+}
+
+#[test]
+fn test_with_page_urls() {
+    let page = PaginatedResponse::new(vec![1, 2], 6, Some(2), 2)
+        .with_page_urls(&uri("/items?q=x%20y&offset=2&limit=2"));
+    assert_eq!(page.next.as_deref(), Some("/items?q=x+y&limit=2&offset=4"));
+    assert_eq!(
+        page.previous.as_deref(),
+        Some("/items?q=x+y&limit=2&offset=0")
+    );
+
+    let json = serde_json::to_value(&page).unwrap();
+    assert_eq!(json["next"], "/items?q=x+y&limit=2&offset=4");
+    assert_eq!(json["previous"], "/items?q=x+y&limit=2&offset=0");
+}
+
+#[test]
+fn test_with_absolute_page_urls_on_edge_pages() {
+    let first = PaginatedResponse::new(vec![1, 2], 3, Some(2), 0)
+        .with_absolute_page_urls("https://api.example.com/", &uri("/items"));
+    assert_eq!(
+        first.next.as_deref(),
+        Some("https://api.example.com/items?limit=2&offset=2")
+    );
+    assert!(first.previous.is_none());
+
+    let last = PaginatedResponse::new(vec![3], 3, Some(2), 2).with_absolute_page_urls(
+        "https://api.example.com",
+        &uri("http://internal:8080/items"),
+    );
+    assert!(last.next.is_none());
+    assert_eq!(
+        last.previous.as_deref(),
+        Some("https://api.example.com/items?limit=2&offset=0")
+    );
+
+    let json = serde_json::to_value(&last).unwrap();
+    assert!(json.get("next").is_none());
+}
@@ -0,0 +1,61 @@
+// Copyright (C) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use axum::response::IntoResponse;
+use bel7_axum::{ApiError, error_count, record_error, render_metrics};
+use http::StatusCode;
+
+#[test]
+fn test_api_error_response_increments_counter() {
+    let before = error_count(StatusCode::CONFLICT, "Conflict", None);
+    let _ = ApiError::Conflict("duplicate".into()).into_response();
+    let _ = ApiError::Conflict("duplicate".into()).into_response();
+    assert_eq!(
+        error_count(StatusCode::CONFLICT, "Conflict", None),
+        before + 2
+    );
+}
+
+#[test]
+fn test_record_error_with_code() {
+    record_error(
+        StatusCode::BAD_REQUEST,
+        "Bad Request",
+        Some("invalid_email"),
+    );
+    assert!(
+        error_count(
+            StatusCode::BAD_REQUEST,
+            "Bad Request",
+            Some("invalid_email")
+        ) >= 1
+    );
+}
+
+#[test]
+fn test_render_metrics_format() {
+    let _ = ApiError::ServiceUnavailable("maintenance".into()).into_response();
+    record_error(
+        StatusCode::FORBIDDEN,
+        "Forbidden",
+        Some("quota \"exceeded\""),
+    );
+
+    let text = render_metrics();
+    assert!(text.contains("# TYPE api_errors_total counter"));
+    assert!(text.contains("api_errors_total{status=\"503\",error=\"Service Unavailable\"}"));
+    assert!(text.contains(
+        "api_errors_total{status=\"403\",error=\"Forbidden\",code=\"quota \\\"exceeded\\\"\"}"
+    ));
+}
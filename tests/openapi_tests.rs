@@ -0,0 +1,67 @@
+// Copyright (C) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use bel7_axum::{ApiError, ErrorResponse, NotFoundResponse, PaginatedResponse, PaginationQuery};
+use utoipa::openapi::RefOr;
+use utoipa::{IntoParams, IntoResponses, PartialSchema, ToResponse, ToSchema};
+
+#[derive(ToSchema)]
+#[allow(dead_code)]
+struct User {
+    id: u64,
+}
+
+#[test]
+fn test_error_response_schema() {
+    assert_eq!(ErrorResponse::name(), "ErrorResponse");
+    let json = serde_json::to_value(ErrorResponse::schema()).unwrap();
+    assert!(json["properties"]["error"].is_object());
+    assert!(json["properties"]["details"].is_object());
+    assert_eq!(json["required"], serde_json::json!(["error"]));
+}
+
+#[test]
+fn test_paginated_response_schema() {
+    let json = serde_json::to_value(PaginatedResponse::<User>::schema()).unwrap();
+    for field in ["data", "total", "limit", "offset", "has_more"] {
+        assert!(json["properties"][field].is_object(), "missing {field}");
+    }
+}
+
+#[test]
+fn test_pagination_query_params() {
+    let params = PaginationQuery::into_params(|| None);
+    let names: Vec<_> = params.iter().map(|p| p.name.as_str()).collect();
+    assert_eq!(names, vec!["limit", "offset"]);
+}
+
+#[test]
+fn test_api_error_responses() {
+    let responses = ApiError::responses();
+    let statuses: Vec<_> = responses.keys().map(String::as_str).collect();
+    assert_eq!(
+        statuses,
+        vec!["400", "401", "403", "404", "409", "422", "500", "503"]
+    );
+
+    let (name, response) = NotFoundResponse::response();
+    assert_eq!(name, "NotFoundResponse");
+    match response {
+        RefOr::T(response) => {
+            assert_eq!(response.description, "Not Found");
+            assert!(response.content.contains_key("application/json"));
+        }
+        RefOr::Ref(_) => panic!("expected an inline response"),
+    }
+}
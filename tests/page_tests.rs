@@ -0,0 +1,140 @@
+// Copyright (C) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use bel7_axum::{
+    ApiError, PageQuery, PagedResponse, PaginatedResponse, PaginationError, PaginationQuery,
+    TotalKind,
+};
+
+#[test]
+fn test_page_query_defaults() {
+    let q = PageQuery::default();
+    assert_eq!(q.effective_page(), 1);
+    assert_eq!(q.effective_per_page(50), 50);
+
+    let q = PageQuery {
+        page: Some(0),
+        per_page: Some(0),
+    };
+    assert_eq!(q.effective_page(), 1);
+    assert_eq!(q.effective_per_page(50), 1);
+}
+
+#[test]
+fn test_page_query_to_pagination_query() {
+    let q = PageQuery {
+        page: Some(4),
+        per_page: Some(500),
+    };
+    let p = q.to_pagination_query(100);
+    assert_eq!(p.limit, Some(100));
+    assert_eq!(p.offset, Some(300));
+}
+
+#[test]
+fn test_page_query_from_pagination_query() {
+    let p = PaginationQuery {
+        limit: Some(20),
+        offset: Some(40),
+    };
+    let q = PageQuery::try_from(&p).unwrap();
+    assert_eq!(q.page, Some(3));
+    assert_eq!(q.per_page, Some(20));
+    assert_eq!(q.to_pagination_query(100).offset, Some(40));
+
+    let unaligned = PaginationQuery {
+        limit: Some(20),
+        offset: Some(30),
+    };
+    assert_eq!(
+        PageQuery::try_from(&unaligned),
+        Err(PaginationError::UnalignedOffset {
+            offset: 30,
+            limit: 20
+        })
+    );
+    assert_eq!(
+        PageQuery::try_from(&PaginationQuery::default()),
+        Err(PaginationError::MissingLimit)
+    );
+}
+
+#[test]
+fn test_paged_response_metadata() {
+    let first = PagedResponse::new(vec![1, 2, 3], 7, 1, 3);
+    assert_eq!(first.total_pages, 3);
+    assert_eq!(first.next_page, Some(2));
+    assert_eq!(first.prev_page, None);
+
+    let last = PagedResponse::new(vec![7], 7, 3, 3);
+    assert_eq!(last.next_page, None);
+    assert_eq!(last.prev_page, Some(2));
+
+    let empty: PagedResponse<i32> = PagedResponse::new(vec![], 0, 1, 10);
+    assert_eq!(empty.total_pages, 0);
+    assert_eq!(empty.next_page, None);
+}
+
+#[test]
+fn test_paged_response_roundtrip() {
+    let paged = PagedResponse::new(vec![4, 5, 6], 7, 2, 3);
+    let offset: PaginatedResponse<i32> = paged.clone().into();
+    assert_eq!(offset.offset, 3);
+    assert_eq!(offset.limit, Some(3));
+    assert!(offset.has_more);
+
+    let back = PagedResponse::try_from(offset).unwrap();
+    assert_eq!(back, paged);
+}
+
+#[test]
+fn test_unaligned_response_conversion_fails() {
+    let offset = PaginatedResponse::new(vec![1, 2], 10, Some(2), 3);
+    let err = PagedResponse::try_from(offset).unwrap_err();
+    let api_error: ApiError = err.into();
+    assert!(matches!(api_error, ApiError::BadRequest(_)));
+}
+
+#[test]
+fn test_inexact_total_response_conversion_fails() {
+    let unknown = PaginatedResponse::from_probe(vec![1, 2, 3], 2, 4);
+    assert!(unknown.has_more);
+    assert_eq!(
+        PagedResponse::try_from(unknown).unwrap_err(),
+        PaginationError::InexactTotal {
+            kind: TotalKind::Unknown
+        }
+    );
+
+    let estimated = PaginatedResponse::new(vec![1, 2], 4, Some(2), 2).with_estimated_total(100);
+    let err = PagedResponse::try_from(estimated).unwrap_err();
+    assert_eq!(
+        err,
+        PaginationError::InexactTotal {
+            kind: TotalKind::Estimated
+        }
+    );
+    let api_error: ApiError = err.into();
+    assert!(matches!(api_error, ApiError::Internal(_)));
+}
+
+#[test]
+fn test_page_zero_into_paginated_response() {
+    let paged: PagedResponse<u32> =
+        serde_json::from_str(r#"{"data": [1, 2], "total": 5, "page": 0, "per_page": 2, "total_pages": 3, "next_page": 1, "prev_page": null}"#)
+            .unwrap();
+    let response = PaginatedResponse::from(paged);
+    assert_eq!(response.offset, 0);
+    assert!(response.has_more);
+}
@@ -0,0 +1,107 @@
+// Copyright (C) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use axum::Router;
+use axum::body::Body;
+use axum::routing::get;
+use bel7_axum::{ApiError, ClampedPagination, Pagination};
+use http::{Request, StatusCode};
+use tower::ServiceExt;
+
+async fn strict(p: Pagination<10, 50>) -> String {
+    format!("{} {}", p.limit, p.offset)
+}
+
+async fn clamped(p: ClampedPagination<10, 50>) -> String {
+    format!("{} {}", p.limit, p.offset)
+}
+
+async fn call(uri: &str) -> (StatusCode, String) {
+    let app = Router::new()
+        .route("/strict", get(strict))
+        .route("/clamped", get(clamped));
+    let response = app
+        .oneshot(Request::builder().uri(uri).body(Body::empty()).unwrap())
+        .await
+        .unwrap();
+    let status = response.status();
+    let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    (status, String::from_utf8(bytes.to_vec()).unwrap())
+}
+
+#[test]
+fn test_defaults() {
+    let p = Pagination::<10, 50>::from_query("").unwrap();
+    assert_eq!((p.limit, p.offset), (10, 0));
+
+    let p = Pagination::<200, 50>::from_query("other=1").unwrap();
+    assert_eq!(p.limit, 50);
+}
+
+#[test]
+fn test_strict_bounds() {
+    assert!(Pagination::<10, 50>::from_query("limit=50&offset=5").is_ok());
+    assert!(matches!(
+        Pagination::<10, 50>::from_query("limit=51"),
+        Err(ApiError::BadRequest(ref msg)) if msg == "limit must not exceed 50"
+    ));
+    assert!(Pagination::<10, 50>::from_query("limit=0").is_err());
+    assert!(Pagination::<10, 50>::from_query("offset=-1").is_err());
+}
+
+#[test]
+fn test_clamping() {
+    let p = ClampedPagination::<10, 50>::from_query("limit=500").unwrap();
+    assert_eq!(p.limit, 50);
+    let p = ClampedPagination::<10, 50>::from_query("limit=0").unwrap();
+    assert_eq!(p.limit, 1);
+    assert!(ClampedPagination::<10, 50>::from_query("limit=abc").is_err());
+}
+
+#[test]
+fn test_query_and_response() {
+    let p = Pagination::<10, 50>::from_query("limit=2&offset=4").unwrap();
+    let q = p.query();
+    assert_eq!((q.limit, q.offset), (Some(2), Some(4)));
+
+    let resp = p.response(vec!['e', 'f'], 7);
+    assert_eq!(resp.limit, Some(2));
+    assert!(resp.has_more);
+}
+
+#[tokio::test]
+async fn test_extractor_accepts_valid_query() {
+    assert_eq!(
+        call("/strict?limit=5&offset=15").await,
+        (StatusCode::OK, "5 15".into())
+    );
+    assert_eq!(
+        call("/clamped?limit=99").await,
+        (StatusCode::OK, "50 0".into())
+    );
+}
+
+#[tokio::test]
+async fn test_extractor_rejects_with_api_error_body() {
+    let (status, body) = call("/strict?limit=abc").await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    let json: serde_json::Value = serde_json::from_str(&body).unwrap();
+    assert_eq!(json["error"], "Bad Request");
+    assert_eq!(
+        json["details"],
+        "limit must be a non-negative integer, got 'abc'"
+    );
+}
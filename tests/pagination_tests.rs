@@ -12,7 +12,12 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use bel7_axum::{PaginatedResponse, PaginationQuery};
+use axum::response::IntoResponse;
+use bel7_axum::{
+    ApiError, EnvelopeFields, PaginatedResponse, PaginationError, PaginationQuery, PaginationStyle,
+    PastEndPolicy, TotalKind, X_LIMIT, X_OFFSET, X_TOTAL_COUNT,
+};
+use http::StatusCode;
 
 #[test]
 fn test_paginated_response_has_more() {
@@ -53,3 +58,265 @@ fn test_pagination_query_clamping() {
     assert_eq!(q.effective_limit(100), 100);
     assert_eq!(q.effective_offset(), 10);
 }
+
+#[test]
+fn test_from_slice() {
+    let items: Vec<u32> = (1..=10).collect();
+
+    let q = PaginationQuery {
+        limit: Some(3),
+        offset: Some(8),
+    };
+    let page = PaginatedResponse::from_slice(&items, &q, 100);
+    assert_eq!(page.data, vec![9, 10]);
+    assert_eq!(page.total, 10);
+    assert_eq!(page.limit, Some(3));
+    assert!(!page.has_more);
+
+    let q = PaginationQuery {
+        limit: Some(50),
+        offset: None,
+    };
+    let page = PaginatedResponse::from_slice(&items, &q, 4);
+    assert_eq!(page.data, vec![1, 2, 3, 4]);
+    assert!(page.has_more);
+}
+
+#[test]
+fn test_from_slice_offset_past_end() {
+    let q = PaginationQuery {
+        limit: Some(5),
+        offset: Some(u64::MAX),
+    };
+    let page = PaginatedResponse::from_slice(&[1, 2, 3], &q, 100);
+    assert!(page.data.is_empty());
+    assert_eq!(page.total, 3);
+    assert!(!page.has_more);
+}
+
+#[test]
+fn test_from_iter() {
+    let q = PaginationQuery {
+        limit: Some(2),
+        offset: Some(1),
+    };
+    let page = PaginatedResponse::from_iter("abcde".chars(), &q, 100);
+    assert_eq!(page.data, vec!['b', 'c']);
+    assert_eq!(page.total, 5);
+    assert!(page.has_more);
+}
+
+#[test]
+fn test_from_probe() {
+    let page = PaginatedResponse::from_probe(vec![1, 2, 3, 4], 3, 6);
+    assert_eq!(page.data, vec![1, 2, 3]);
+    assert!(page.has_more);
+    assert_eq!(page.total, 9);
+    assert_eq!(page.total_kind, TotalKind::Unknown);
+
+    let last = PaginatedResponse::from_probe(vec![1], 3, 9);
+    assert!(!last.has_more);
+    assert_eq!(last.total, 10);
+}
+
+#[test]
+fn test_with_estimated_total() {
+    let page = PaginatedResponse::from_probe(vec![1, 2, 3], 2, 0).with_estimated_total(1_000);
+    assert_eq!(page.total, 1_000);
+    assert_eq!(page.total_kind, TotalKind::Estimated);
+    assert!(page.has_more);
+    assert!(!page.is_total_exact());
+
+    // stale statistics cannot undercount what was already seen
+    let page = PaginatedResponse::new(vec![1, 2], 12, Some(2), 10).with_estimated_total(5);
+    assert_eq!(page.total, 12);
+}
+
+#[test]
+fn test_total_kind_serialization() {
+    let exact = serde_json::to_value(PaginatedResponse::single_page(vec![1])).unwrap();
+    assert_eq!(
+        exact,
+        serde_json::json!({"data": [1], "total": 1, "offset": 0, "has_more": false})
+    );
+
+    let unknown = serde_json::to_value(PaginatedResponse::from_probe(vec![1, 2], 1, 0)).unwrap();
+    assert_eq!(unknown["total_kind"], "unknown");
+
+    let legacy: PaginatedResponse<i32> =
+        serde_json::from_str(r#"{"data": [], "total": 0, "offset": 0, "has_more": false}"#)
+            .unwrap();
+    assert_eq!(legacy.total_kind, TotalKind::Exact);
+}
+
+#[test]
+fn test_headers_omit_inexact_total() {
+    let headers = PaginatedResponse::from_probe(vec![1, 2], 1, 0).pagination_headers();
+    assert!(headers.get(X_TOTAL_COUNT).is_none());
+    assert_eq!(headers[X_LIMIT], "1");
+}
+
+#[tokio::test]
+async fn test_into_response_envelope() {
+    let resp = PaginatedResponse::new(vec![1, 2], 5, Some(2), 0).into_response();
+    assert_eq!(resp.status(), StatusCode::OK);
+    assert!(resp.headers().get(X_TOTAL_COUNT).is_none());
+
+    let json = body_json(resp).await;
+    assert_eq!(json["data"], serde_json::json!([1, 2]));
+    assert_eq!(json["total"], 5);
+    assert_eq!(json["has_more"], true);
+}
+
+#[tokio::test]
+async fn test_into_response_headers_style() {
+    let resp = PaginatedResponse::new(vec![3, 4], 5, Some(2), 2)
+        .into_response_with(PaginationStyle::Headers);
+    assert_eq!(resp.headers()[X_TOTAL_COUNT], "5");
+    assert_eq!(resp.headers()[X_OFFSET], "2");
+    assert_eq!(resp.headers()[X_LIMIT], "2");
+
+    let json = body_json(resp).await;
+    assert_eq!(json, serde_json::json!([3, 4]));
+}
+
+#[tokio::test]
+async fn test_into_response_named_envelope() {
+    let fields = EnvelopeFields::new()
+        .data("items")
+        .total("count")
+        .limit("page_size")
+        .has_more("next")
+        .without_offset()
+        .without_total_kind();
+    let resp = PaginatedResponse::new(vec![3, 4], 5, Some(2), 2)
+        .into_response_with(PaginationStyle::NamedEnvelope(fields));
+
+    let json = body_json(resp).await;
+    assert_eq!(
+        json,
+        serde_json::json!({"items": [3, 4], "count": 5, "page_size": 2, "next": true})
+    );
+}
+
+#[test]
+fn test_named_envelope_page_urls() {
+    let uri: http::Uri = "/items?limit=2&offset=2".parse().unwrap();
+    let page = PaginatedResponse::new(vec![3, 4], 6, Some(2), 2).with_page_urls(&uri);
+
+    let legacy = EnvelopeFields::new().data("items").has_more("next");
+    let json = serde_json::to_value(page.with_fields(legacy)).unwrap();
+    assert_eq!(json["next"], true);
+    assert!(json.get("previous").is_none());
+
+    let json = serde_json::to_value(page.with_fields(legacy.next("next_url"))).unwrap();
+    assert_eq!(json["next_url"], "/items?limit=2&offset=4");
+
+    let defaults = EnvelopeFields::new().with_page_urls();
+    assert_eq!(
+        serde_json::to_value(page.with_fields(defaults)).unwrap(),
+        serde_json::to_value(&page).unwrap()
+    );
+}
+
+#[test]
+#[should_panic(expected = "duplicate envelope field name")]
+fn test_duplicate_envelope_field_names() {
+    let _ = EnvelopeFields::new().has_more("next").with_page_urls();
+}
+
+#[test]
+fn test_default_fields_match_derived_serialization() {
+    let page = PaginatedResponse::new(vec![1, 2], 5, Some(2), 0);
+    assert_eq!(
+        serde_json::to_value(page.with_fields(EnvelopeFields::default())).unwrap(),
+        serde_json::to_value(&page).unwrap()
+    );
+
+    let page = PaginatedResponse::single_page(vec![1]);
+    let json = serde_json::to_value(page.with_fields(EnvelopeFields::default())).unwrap();
+    assert!(json.get("limit").is_none());
+}
+
+#[test]
+fn test_pagination_headers_without_limit() {
+    let headers = PaginatedResponse::single_page(vec![1]).pagination_headers();
+    assert_eq!(headers[X_TOTAL_COUNT], "1");
+    assert!(headers.get(X_LIMIT).is_none());
+}
+
+#[test]
+fn test_new_does_not_overflow() {
+    let resp = PaginatedResponse::new(vec![1, 2], u64::MAX, Some(2), u64::MAX - 1);
+    assert!(!resp.has_more);
+}
+
+#[test]
+fn test_try_new_rejects_inconsistent_inputs() {
+    assert_eq!(
+        PaginatedResponse::try_new(vec![1], 10, None, u64::MAX).unwrap_err(),
+        PaginationError::Overflow {
+            offset: u64::MAX,
+            returned: 1
+        }
+    );
+    assert_eq!(
+        PaginatedResponse::try_new(vec![1, 2], 5, Some(2), 4).unwrap_err(),
+        PaginationError::ExceedsTotal {
+            offset: 4,
+            returned: 2,
+            total: 5
+        }
+    );
+
+    let err: ApiError = PaginationError::PageTooLarge {
+        returned: 3,
+        limit: 2,
+    }
+    .into();
+    assert!(matches!(err, ApiError::Internal(_)));
+
+    let page = PaginatedResponse::<u32>::try_new(vec![], 5, Some(2), 10).unwrap();
+    assert!(!page.has_more);
+}
+
+#[test]
+fn test_check_offset_policies() {
+    let past_end = || PaginatedResponse::<u32>::new(vec![], 5, Some(2), 6);
+
+    assert!(past_end().check_offset(PastEndPolicy::EmptyPage).is_ok());
+    assert!(matches!(
+        past_end().check_offset(PastEndPolicy::NotFound),
+        Err(ApiError::NotFound(_))
+    ));
+    assert!(matches!(
+        past_end().check_offset(PastEndPolicy::BadRequest),
+        Err(ApiError::BadRequest(ref msg))
+            if msg == "offset 6 is past the end of the collection of 5 items"
+    ));
+
+    // an empty collection is not past its end
+    let empty = PaginatedResponse::<u32>::new(vec![], 0, Some(2), 0);
+    assert!(empty.check_offset(PastEndPolicy::NotFound).is_ok());
+
+    // the exact total decides, not the number of returned items
+    let within = PaginatedResponse::<u32>::new(vec![], 10, Some(0), 3);
+    assert!(within.check_offset(PastEndPolicy::NotFound).is_ok());
+
+    // an estimate may be too low, only an empty page is past the end
+    let estimated = PaginatedResponse::new(vec![1], 10, Some(2), 0).with_estimated_total(5);
+    let estimated = PaginatedResponse {
+        offset: 8,
+        ..estimated
+    };
+    assert!(estimated.check_offset(PastEndPolicy::NotFound).is_ok());
+    let probed = PaginatedResponse::<u32>::from_probe(vec![], 2, 8);
+    assert!(probed.check_offset(PastEndPolicy::NotFound).is_err());
+}
+
+async fn body_json(response: axum::response::Response) -> serde_json::Value {
+    let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    serde_json::from_slice(&bytes).unwrap()
+}
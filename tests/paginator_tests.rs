@@ -0,0 +1,122 @@
+// Copyright (C) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use bel7_axum::{
+    CursorPaginatedResponse, CursorPaginator, CursorQuery, PaginatedResponse, PaginationQuery,
+    Paginator,
+};
+use futures_util::{StreamExt, TryStreamExt};
+
+#[derive(Clone)]
+struct Numbers {
+    items: Vec<u32>,
+    fail_at_offset: Option<u64>,
+    calls: Arc<AtomicUsize>,
+}
+
+impl Numbers {
+    fn new(n: u32) -> Self {
+        Self {
+            items: (1..=n).collect(),
+            fail_at_offset: None,
+            calls: Arc::new(AtomicUsize::new(0)),
+        }
+    }
+}
+
+impl Paginator for Numbers {
+    type Item = u32;
+    type Error = String;
+
+    async fn fetch_page(&self, query: PaginationQuery) -> Result<PaginatedResponse<u32>, String> {
+        self.calls.fetch_add(1, Ordering::SeqCst);
+        if self.fail_at_offset == query.offset {
+            return Err("boom".into());
+        }
+        Ok(PaginatedResponse::from_slice(&self.items, &query, 100))
+    }
+}
+
+impl CursorPaginator for Numbers {
+    type Item = u32;
+    type Error = String;
+
+    async fn fetch_page(&self, query: CursorQuery) -> Result<CursorPaginatedResponse<u32>, String> {
+        let after: u32 = query.cursor.as_deref().map_or(0, |c| c.parse().unwrap());
+        let limit = query.effective_limit(100) as usize;
+        let rows: Vec<u32> = self
+            .items
+            .iter()
+            .copied()
+            .filter(|&n| n > after)
+            .take(limit + 1)
+            .collect();
+        Ok(CursorPaginatedResponse::from_probe(
+            rows,
+            limit as u64,
+            &query,
+            |n| *n,
+        ))
+    }
+}
+
+#[tokio::test]
+async fn test_pages() {
+    let source = Numbers::new(7);
+    let calls = source.calls.clone();
+    let pages: Vec<_> = Paginator::pages(source, 3).try_collect().await.unwrap();
+    assert_eq!(pages.len(), 3);
+    assert_eq!(pages[2].data, vec![7]);
+    assert_eq!(pages[2].offset, 6);
+    assert_eq!(calls.load(Ordering::SeqCst), 3);
+}
+
+#[tokio::test]
+async fn test_items() {
+    let items: Vec<u32> = Paginator::items(Numbers::new(10), 4)
+        .try_collect()
+        .await
+        .unwrap();
+    assert_eq!(items, (1..=10).collect::<Vec<_>>());
+}
+
+#[tokio::test]
+async fn test_empty_source() {
+    let items: Vec<u32> = Paginator::items(Numbers::new(0), 4)
+        .try_collect()
+        .await
+        .unwrap();
+    assert!(items.is_empty());
+}
+
+#[tokio::test]
+async fn test_error_ends_stream() {
+    let mut source = Numbers::new(10);
+    source.fail_at_offset = Some(4);
+    let results: Vec<Result<u32, String>> = Paginator::items(source, 4).collect().await;
+    assert_eq!(results.len(), 5);
+    assert_eq!(results[4], Err("boom".to_string()));
+}
+
+#[tokio::test]
+async fn test_cursor_items() {
+    let items: Vec<u32> = CursorPaginator::items(Numbers::new(8), 3)
+        .try_collect()
+        .await
+        .unwrap();
+    assert_eq!(items, (1..=8).collect::<Vec<_>>());
+}
@@ -0,0 +1,141 @@
+// Copyright (C) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use axum::Router;
+use axum::body::Body;
+use axum::response::Response;
+use axum::routing::get;
+use bel7_axum::{ApiError, ItemRange, PaginatedResponse};
+use http::{Request, StatusCode, header};
+use tower::ServiceExt;
+
+const ITEMS: [u32; 7] = [0, 1, 2, 3, 4, 5, 6];
+
+async fn list(range: Option<ItemRange>) -> Response {
+    let query = range.map(|r| r.to_pagination_query(3)).unwrap_or_default();
+    PaginatedResponse::from_slice(&ITEMS, &query, 3).into_range_response(range)
+}
+
+async fn call(range: Option<&str>) -> Response {
+    let app = Router::new().route("/items", get(list));
+    let mut request = Request::builder().uri("/items");
+    if let Some(range) = range {
+        request = request.header(header::RANGE, range);
+    }
+    app.oneshot(request.body(Body::empty()).unwrap())
+        .await
+        .unwrap()
+}
+
+async fn body_string(response: Response) -> String {
+    let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    String::from_utf8(bytes.to_vec()).unwrap()
+}
+
+#[test]
+fn test_parse() {
+    assert_eq!(
+        ItemRange::parse("items=0-24").unwrap(),
+        ItemRange {
+            first: 0,
+            last: Some(24)
+        }
+    );
+    assert_eq!(
+        ItemRange::parse(" Items = 25- ").unwrap(),
+        ItemRange {
+            first: 25,
+            last: None
+        }
+    );
+
+    for invalid in [
+        "items",
+        "items=5-4",
+        "items=-5",
+        "items=a-b",
+        "bytes=0-1",
+        "items=0-1,4-5",
+    ] {
+        assert!(
+            matches!(ItemRange::parse(invalid), Err(ApiError::BadRequest(_))),
+            "{invalid}"
+        );
+    }
+}
+
+#[test]
+fn test_to_pagination_query() {
+    let q = ItemRange::parse("items=10-59")
+        .unwrap()
+        .to_pagination_query(20);
+    assert_eq!((q.limit, q.offset), (Some(20), Some(10)));
+
+    let q = ItemRange::parse("items=10-")
+        .unwrap()
+        .to_pagination_query(20);
+    assert_eq!((q.limit, q.offset), (Some(20), Some(10)));
+}
+
+#[tokio::test]
+async fn test_partial_content() {
+    let response = call(Some("items=2-3")).await;
+    assert_eq!(response.status(), StatusCode::PARTIAL_CONTENT);
+    assert_eq!(response.headers()[header::CONTENT_RANGE], "items 2-3/7");
+    assert_eq!(response.headers()[header::ACCEPT_RANGES], "items");
+    assert_eq!(body_string(response).await, "[2,3]");
+
+    // the limit is clamped, the response describes the returned range
+    let response = call(Some("items=5-")).await;
+    assert_eq!(response.headers()[header::CONTENT_RANGE], "items 5-6/7");
+    assert_eq!(body_string(response).await, "[5,6]");
+}
+
+#[tokio::test]
+async fn test_without_range_header() {
+    let response = call(None).await;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert!(response.headers().get(header::CONTENT_RANGE).is_none());
+    assert_eq!(response.headers()[header::ACCEPT_RANGES], "items");
+    assert_eq!(body_string(response).await, "[0,1,2]");
+}
+
+#[tokio::test]
+async fn test_range_not_satisfiable() {
+    let response = call(Some("items=7-9")).await;
+    assert_eq!(response.status(), StatusCode::RANGE_NOT_SATISFIABLE);
+    assert_eq!(response.headers()[header::CONTENT_RANGE], "items */7");
+}
+
+#[tokio::test]
+async fn test_malformed_range() {
+    let response = call(Some("items=9-7")).await;
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn test_empty_collection_and_inexact_total() {
+    let range = ItemRange::parse("items=0-9").ok();
+    let response = PaginatedResponse::<u32>::new(vec![], 0, Some(10), 0).into_range_response(range);
+    assert_eq!(response.status(), StatusCode::OK);
+    assert!(response.headers().get(header::CONTENT_RANGE).is_none());
+
+    let page = PaginatedResponse::from_probe(vec![1, 2, 3], 2, 0);
+    assert_eq!(page.content_range(), "items 0-1/*");
+    assert_eq!(
+        page.into_range_response(range).status(),
+        StatusCode::PARTIAL_CONTENT
+    );
+}
@@ -0,0 +1,123 @@
+// Copyright (C) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use axum::Router;
+use axum::body::Body;
+use axum::routing::get;
+use bel7_axum::{ApiError, ListQuery, SortDirection, SortField, SortSpec};
+use http::{Request, StatusCode};
+use tower::ServiceExt;
+
+const SORTABLE: &[&str] = &["created_at", "name", "id"];
+
+#[test]
+fn test_parse_sort_spec() {
+    let spec = SortSpec::parse("-created_at, +name,id", SORTABLE).unwrap();
+    assert_eq!(
+        spec.fields,
+        vec![
+            SortField {
+                name: "created_at".into(),
+                direction: SortDirection::Desc
+            },
+            SortField {
+                name: "name".into(),
+                direction: SortDirection::Asc
+            },
+            SortField {
+                name: "id".into(),
+                direction: SortDirection::Asc
+            },
+        ]
+    );
+    assert_eq!(spec.to_string(), "-created_at,name,id");
+}
+
+#[test]
+fn test_parse_rejects_unknown_and_duplicate_fields() {
+    let err = SortSpec::parse("-email", SORTABLE).unwrap_err();
+    assert!(matches!(err, ApiError::BadRequest(ref msg) if msg.contains("'email'")));
+    assert!(SortSpec::parse("name,-name", SORTABLE).is_err());
+}
+
+#[test]
+fn test_empty_and_reversed_spec() {
+    let spec = SortSpec::parse(" , ", SORTABLE).unwrap();
+    assert!(spec.is_empty());
+    assert_eq!(spec.order_by(), None);
+
+    let spec = SortSpec::parse("-created_at,id", SORTABLE).unwrap();
+    assert_eq!(
+        spec.reversed().order_by().as_deref(),
+        Some("created_at ASC, id DESC")
+    );
+}
+
+#[test]
+fn test_list_query_parsing() {
+    let q = ListQuery::from_query("sort=-name&status=active&limit=5&offset=10&q=a%20b").unwrap();
+    assert_eq!(q.pagination.limit, Some(5));
+    assert_eq!(q.pagination.offset, Some(10));
+    assert_eq!(q.filter("status"), Some("active"));
+    assert_eq!(q.filter("q"), Some("a b"));
+    assert_eq!(q.filter("sort"), None);
+
+    let spec = q.sort_spec(SORTABLE).unwrap();
+    assert_eq!(spec.fields[0].direction, SortDirection::Desc);
+    assert!(ListQuery::default().sort_spec(SORTABLE).unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_list_query_extractor() {
+    async fn handler(q: ListQuery) -> Result<String, ApiError> {
+        let spec = q.sort_spec(SORTABLE)?;
+        Ok(format!("{} {:?}", spec, q.filter("status")))
+    }
+    let app = Router::new().route("/", get(handler));
+
+    let ok = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .uri("/?sort=-id&status=new")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(ok.status(), StatusCode::OK);
+
+    let bad_sort = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .uri("/?sort=secret")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(bad_sort.status(), StatusCode::BAD_REQUEST);
+
+    let bad_limit = app
+        .oneshot(
+            Request::builder()
+                .uri("/?limit=many")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(bad_limit.status(), StatusCode::BAD_REQUEST);
+}
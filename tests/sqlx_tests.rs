@@ -0,0 +1,156 @@
+// Copyright (C) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use bel7_axum::{ApiError, PaginationQuery};
+use sqlx::sqlite::SqlitePoolOptions;
+use sqlx::{FromRow, QueryBuilder, Sqlite, SqlitePool};
+
+#[derive(Debug, PartialEq, FromRow)]
+struct User {
+    id: i64,
+    name: String,
+}
+
+async fn pool() -> SqlitePool {
+    let pool = SqlitePoolOptions::new()
+        .max_connections(1)
+        .connect("sqlite::memory:")
+        .await
+        .unwrap();
+    sqlx::query("CREATE TABLE users (id INTEGER PRIMARY KEY, name TEXT NOT NULL, active INTEGER)")
+        .execute(&pool)
+        .await
+        .unwrap();
+    for id in 1..=12 {
+        sqlx::query("INSERT INTO users (id, name, active) VALUES (?, ?, ?)")
+            .bind(id)
+            .bind(format!("user-{id}"))
+            .bind(id % 3 != 0)
+            .execute(&pool)
+            .await
+            .unwrap();
+    }
+    pool
+}
+
+#[test]
+fn test_push_limit_offset() {
+    let q = PaginationQuery {
+        limit: Some(500),
+        offset: Some(20),
+    };
+    let mut builder: QueryBuilder<Sqlite> = QueryBuilder::new("SELECT * FROM users");
+    q.push_limit_offset(&mut builder, 100);
+    assert_eq!(builder.sql(), "SELECT * FROM users LIMIT ? OFFSET ?");
+}
+
+#[tokio::test]
+async fn test_fetch_paginated() {
+    let pool = pool().await;
+    let q = PaginationQuery {
+        limit: Some(5),
+        offset: Some(5),
+    };
+
+    let mut query = QueryBuilder::new("SELECT id, name FROM users ORDER BY id");
+    let mut count = QueryBuilder::new("SELECT COUNT(*) FROM users");
+    let page = q
+        .fetch_paginated::<_, _, User>(&pool, &mut query, &mut count, 100)
+        .await
+        .unwrap();
+
+    assert_eq!(page.total, 12);
+    assert_eq!(page.offset, 5);
+    assert_eq!(page.limit, Some(5));
+    assert!(page.has_more);
+    let ids: Vec<i64> = page.data.iter().map(|u| u.id).collect();
+    assert_eq!(ids, vec![6, 7, 8, 9, 10]);
+}
+
+#[tokio::test]
+async fn test_fetch_paginated_with_filters() {
+    let pool = pool().await;
+    let q = PaginationQuery {
+        limit: Some(10),
+        offset: Some(5),
+    };
+
+    let mut query = QueryBuilder::new("SELECT id, name FROM users WHERE active = ");
+    query.push_bind(true).push(" ORDER BY id");
+    let mut count = QueryBuilder::new("SELECT COUNT(*) FROM users WHERE active = ");
+    count.push_bind(true);
+
+    let page = q
+        .fetch_paginated::<_, _, User>(&pool, &mut query, &mut count, 3)
+        .await
+        .unwrap();
+
+    assert_eq!(page.total, 8);
+    assert_eq!(page.limit, Some(3));
+    assert_eq!(
+        page.data,
+        vec![
+            User {
+                id: 8,
+                name: "user-8".into()
+            },
+            User {
+                id: 10,
+                name: "user-10".into()
+            },
+            User {
+                id: 11,
+                name: "user-11".into()
+            },
+        ]
+    );
+    assert!(!page.has_more);
+}
+
+#[tokio::test]
+async fn test_fetch_paginated_in_transaction() {
+    let pool = pool().await;
+    let mut tx = pool.begin().await.unwrap();
+    sqlx::query("INSERT INTO users (id, name, active) VALUES (13, 'user-13', 1)")
+        .execute(&mut *tx)
+        .await
+        .unwrap();
+
+    let q = PaginationQuery {
+        limit: Some(5),
+        offset: Some(10),
+    };
+    let mut query = QueryBuilder::new("SELECT id, name FROM users ORDER BY id");
+    let mut count = QueryBuilder::new("SELECT COUNT(*) FROM users");
+    let page = q
+        .fetch_paginated::<_, _, User>(&mut *tx, &mut query, &mut count, 100)
+        .await
+        .unwrap();
+
+    assert_eq!(page.total, 13);
+    let ids: Vec<i64> = page.data.iter().map(|u| u.id).collect();
+    assert_eq!(ids, vec![11, 12, 13]);
+}
+
+#[tokio::test]
+async fn test_fetch_paginated_negative_count() {
+    let pool = pool().await;
+    let mut query = QueryBuilder::new("SELECT id, name FROM users");
+    let mut count = QueryBuilder::new("SELECT -1");
+    let err = PaginationQuery::default()
+        .fetch_paginated::<_, _, User>(&pool, &mut query, &mut count, 100)
+        .await
+        .unwrap_err();
+    assert!(matches!(err, ApiError::Internal(ref msg) if msg.contains("negative")));
+}
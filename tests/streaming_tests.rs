@@ -0,0 +1,87 @@
+// Copyright (C) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use axum::response::{IntoResponse, Response};
+use bel7_axum::{ApiError, NDJSON_CONTENT_TYPE, StreamingResponse};
+use futures_util::stream;
+use http::header;
+use serde_json::json;
+
+async fn body_string(response: Response) -> String {
+    let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    String::from_utf8(bytes.to_vec()).unwrap()
+}
+
+fn items(n: u32) -> impl futures_util::Stream<Item = Result<u32, ApiError>> {
+    stream::iter((1..=n).map(Ok))
+}
+
+#[tokio::test]
+async fn test_ndjson() {
+    let response = StreamingResponse::ndjson(items(2))
+        .with_offset(10)
+        .with_total(12)
+        .into_response();
+    assert_eq!(
+        response.headers()[header::CONTENT_TYPE],
+        NDJSON_CONTENT_TYPE
+    );
+    assert_eq!(
+        body_string(response).await,
+        "1\n2\n{\"meta\":{\"count\":2,\"offset\":10,\"total\":12}}\n"
+    );
+}
+
+#[tokio::test]
+async fn test_json_array() {
+    let response = StreamingResponse::json_array(items(3)).into_response();
+    assert_eq!(response.headers()[header::CONTENT_TYPE], "application/json");
+    let json: serde_json::Value = serde_json::from_str(&body_string(response).await).unwrap();
+    assert_eq!(json, json!([1, 2, 3, {"meta": {"count": 3, "offset": 0}}]));
+
+    let empty = StreamingResponse::json_array(items(0)).without_metadata();
+    assert_eq!(body_string(empty.into_response()).await, "[]");
+
+    let response = StreamingResponse::json_array(items(2)).without_metadata();
+    assert_eq!(body_string(response.into_response()).await, "[1,2]");
+}
+
+#[tokio::test]
+async fn test_mid_stream_error() {
+    let failing = stream::iter(vec![
+        Ok(1),
+        Err(ApiError::ServiceUnavailable(
+            "database is unavailable".into(),
+        )),
+        Ok(3),
+    ]);
+    let body = body_string(StreamingResponse::ndjson(failing).into_response()).await;
+    let lines: Vec<serde_json::Value> = body
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect();
+    assert_eq!(
+        lines,
+        vec![
+            json!(1),
+            json!({"error": "Service Unavailable", "details": "database is unavailable"})
+        ]
+    );
+
+    let failing = stream::iter(vec![Err::<u32, _>(ApiError::Internal("secret".into()))]);
+    let body = body_string(StreamingResponse::json_array(failing).into_response()).await;
+    let json: serde_json::Value = serde_json::from_str(&body).unwrap();
+    assert_eq!(json, json!([{"error": "Internal Server Error"}]));
+}
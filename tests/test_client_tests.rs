@@ -0,0 +1,135 @@
+// Copyright (C) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use axum::extract::ws::{Message, WebSocket, WebSocketUpgrade};
+use axum::extract::{Path, Query};
+use axum::response::Response;
+use axum::routing::{get, post};
+use axum::{Json, Router};
+use bel7_axum::{ApiError, PaginatedResponse, PaginationQuery, TestClient};
+use futures_util::{SinkExt, StreamExt};
+use http::StatusCode;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct User {
+    id: u64,
+    name: String,
+}
+
+async fn get_user(Path(id): Path<u64>) -> Result<Json<User>, ApiError> {
+    if id == 0 {
+        return Err(ApiError::NotFound(format!("User {id} not found")));
+    }
+    Ok(Json(User {
+        id,
+        name: format!("user-{id}"),
+    }))
+}
+
+async fn create_user(Json(user): Json<User>) -> Result<Json<User>, ApiError> {
+    if user.name.is_empty() {
+        return Err(ApiError::ValidationError("name is required".into()));
+    }
+    Ok(Json(user))
+}
+
+async fn list_numbers(Query(q): Query<PaginationQuery>) -> Json<PaginatedResponse<u64>> {
+    let limit = q.effective_limit(100);
+    let offset = q.effective_offset();
+    let data = (offset..(offset + limit).min(7)).collect();
+    Json(PaginatedResponse::new(data, 7, Some(limit), offset))
+}
+
+async fn echo(ws: WebSocketUpgrade) -> Response {
+    ws.on_upgrade(|mut socket: WebSocket| async move {
+        while let Some(Ok(Message::Text(text))) = socket.recv().await {
+            let _ = socket.send(Message::Text(text)).await;
+        }
+    })
+}
+
+fn app() -> Router {
+    Router::new()
+        .route("/users", post(create_user))
+        .route("/users/{id}", get(get_user))
+        .route("/numbers", get(list_numbers))
+        .route("/echo", get(echo))
+}
+
+#[tokio::test]
+async fn test_get_typed() {
+    let client = TestClient::new(app());
+    let user: User = client.get("/users/7").await.unwrap();
+    assert_eq!(user.name, "user-7");
+}
+
+#[tokio::test]
+async fn test_get_error() {
+    let client = TestClient::new(app());
+    let err = client.get::<User>("/users/0").await.unwrap_err();
+    assert_eq!(err.status, StatusCode::NOT_FOUND);
+    assert!(matches!(err.error, ApiError::NotFound(ref msg) if msg == "User 0 not found"));
+}
+
+#[tokio::test]
+async fn test_unmapped_status_is_preserved() {
+    let client = TestClient::new(app());
+    let err = client.get::<User>("/users").await.unwrap_err();
+    assert_eq!(err.status, StatusCode::METHOD_NOT_ALLOWED);
+}
+
+#[tokio::test]
+async fn test_post_json() {
+    let client = TestClient::new(app());
+    let user = User {
+        id: 1,
+        name: "alice".into(),
+    };
+    let created: User = client.post_json("/users", &user).await.unwrap();
+    assert_eq!(created, user);
+
+    let invalid = User {
+        id: 2,
+        name: String::new(),
+    };
+    let err = client
+        .post_json::<_, User>("/users", &invalid)
+        .await
+        .unwrap_err();
+    assert!(matches!(err.error, ApiError::ValidationError(_)));
+}
+
+#[tokio::test]
+async fn test_get_all_pages() {
+    let client = TestClient::new(app());
+    let all: Vec<u64> = client
+        .get_all_pages("/numbers?limit=50&filter=a%20b", 3)
+        .await
+        .unwrap();
+    assert_eq!(all, vec![0, 1, 2, 3, 4, 5, 6]);
+}
+
+#[tokio::test]
+async fn test_websocket() {
+    let client = TestClient::new(app());
+    let server = client.serve().await.unwrap();
+    let mut ws = server.ws("/echo").await.unwrap();
+
+    ws.send(tokio_tungstenite::tungstenite::Message::text("ping"))
+        .await
+        .unwrap();
+    let reply = ws.next().await.unwrap().unwrap();
+    assert_eq!(reply.into_text().unwrap().as_str(), "ping");
+}
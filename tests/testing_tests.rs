@@ -0,0 +1,63 @@
+// Copyright (C) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use axum::Json;
+use axum::response::IntoResponse;
+use bel7_axum::{
+    ApiError, PaginatedResponse, assert_api_error, assert_paginated, read_error_response,
+};
+use http::StatusCode;
+
+#[tokio::test]
+async fn test_assert_api_error() {
+    let response = ApiError::NotFound("User 7 not found".into()).into_response();
+    let body = assert_api_error(response, StatusCode::NOT_FOUND, Some("User 7 not found")).await;
+    assert_eq!(body.error, "Not Found");
+}
+
+#[tokio::test]
+async fn test_assert_api_error_without_details() {
+    let response = ApiError::Internal("db is down".into()).into_response();
+    assert_api_error(response, StatusCode::INTERNAL_SERVER_ERROR, None).await;
+}
+
+#[tokio::test]
+#[should_panic(expected = "unexpected status code")]
+async fn test_assert_api_error_status_mismatch() {
+    let response = ApiError::Conflict("taken".into()).into_response();
+    assert_api_error(response, StatusCode::NOT_FOUND, Some("taken")).await;
+}
+
+#[tokio::test]
+async fn test_read_error_response() {
+    let response = ApiError::Forbidden("no access".into()).into_response();
+    let (status, body) = read_error_response(response).await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+    assert_eq!(body.details.as_deref(), Some("no access"));
+}
+
+#[tokio::test]
+async fn test_assert_paginated() {
+    let page = PaginatedResponse::new(vec![4, 5, 6], 10, Some(3), 3);
+    let response = Json(page).into_response();
+    let decoded: PaginatedResponse<i32> = assert_paginated(response, 10, true, 3).await;
+    assert_eq!(decoded.data, vec![4, 5, 6]);
+}
+
+#[tokio::test]
+#[should_panic(expected = "unexpected has_more")]
+async fn test_assert_paginated_mismatch() {
+    let response = Json(PaginatedResponse::single_page(vec![1, 2])).into_response();
+    let _: PaginatedResponse<i32> = assert_paginated(response, 2, true, 0).await;
+}
@@ -0,0 +1,104 @@
+// Copyright (C) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::cell::RefCell;
+
+use bel7_axum::{PaginatedResponse, PaginationQuery, walk_page_responses, walk_pages};
+use futures_util::{StreamExt, TryStreamExt};
+
+fn server(query: &PaginationQuery) -> PaginatedResponse<u32> {
+    let items: Vec<u32> = (0..23).collect();
+    PaginatedResponse::from_slice(&items, query, 10)
+}
+
+#[tokio::test]
+async fn test_walk_pages_yields_all_items() {
+    let start = PaginationQuery {
+        limit: Some(5),
+        offset: None,
+    };
+    let seen = RefCell::new(Vec::new());
+    let items: Vec<u32> = walk_pages(start, |q| {
+        seen.borrow_mut().push((q.limit, q.offset));
+        let page = server(&q);
+        async move { Ok::<_, String>(page) }
+    })
+    .try_collect()
+    .await
+    .unwrap();
+
+    assert_eq!(items, (0..23).collect::<Vec<_>>());
+    assert_eq!(
+        seen.into_inner(),
+        vec![
+            (Some(5), Some(0)),
+            (Some(5), Some(5)),
+            (Some(5), Some(10)),
+            (Some(5), Some(15)),
+            (Some(5), Some(20)),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_walk_pages_uses_server_default_limit() {
+    let start = PaginationQuery {
+        limit: None,
+        offset: Some(3),
+    };
+    let pages: Vec<_> = walk_page_responses(start, |q| {
+        let page = server(&q);
+        async move { Ok::<_, String>(page) }
+    })
+    .try_collect()
+    .await
+    .unwrap();
+
+    let sizes: Vec<usize> = pages.iter().map(|p| p.data.len()).collect();
+    assert_eq!(sizes, vec![10, 10]);
+}
+
+#[tokio::test]
+async fn test_walk_pages_stops_on_empty_page() {
+    // a misbehaving server that always claims there is more
+    let items: Vec<u32> = walk_pages(PaginationQuery::default(), |q| async move {
+        let data = if q.effective_offset() == 0 {
+            vec![1, 2]
+        } else {
+            vec![]
+        };
+        Ok::<_, String>(PaginatedResponse {
+            has_more: true,
+            ..PaginatedResponse::new(data, 100, None, q.effective_offset())
+        })
+    })
+    .try_collect()
+    .await
+    .unwrap();
+    assert_eq!(items, vec![1, 2]);
+}
+
+#[tokio::test]
+async fn test_walk_pages_stops_on_error() {
+    let results: Vec<Result<u32, String>> =
+        walk_pages(PaginationQuery::default(), |q| async move {
+            if q.effective_offset() > 0 {
+                return Err("unavailable".to_string());
+            }
+            Ok(PaginatedResponse::new(vec![1], 5, None, 0))
+        })
+        .collect()
+        .await;
+    assert_eq!(results, vec![Ok(1), Err("unavailable".to_string())]);
+}